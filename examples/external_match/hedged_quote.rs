@@ -0,0 +1,40 @@
+//! Request quotes with hedging enabled, then print the client's latency stats.
+//!
+//! A hedge is sent when a quote has not returned by the p95 latency observed
+//! so far, and the first successful response is used.
+
+use std::time::Duration;
+
+use renegade_sdk::example_utils::build_renegade_client;
+use renegade_sdk::{ExternalOrderBuilderV2, HedgeConfig, RequestQuoteOptions};
+
+/// Testnet wETH
+const BASE_MINT: &str = "0xc3414a7ef14aaaa9c4522dfc00a4e66e74e9c25a";
+/// Testnet USDC
+const QUOTE_MINT: &str = "0xdf8d259c04020562717557f2b5a3cf28e92707d1";
+
+/// The number of quotes to request
+const NUM_QUOTES: usize = 10;
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Error> {
+    let client = build_renegade_client(false /* use_base */)?;
+
+    let order = ExternalOrderBuilderV2::new()
+        .input_mint(QUOTE_MINT)
+        .output_mint(BASE_MINT)
+        .input_amount(30_000_000) // $30 USDC
+        .build()
+        .unwrap();
+
+    let hedge = HedgeConfig::new().with_percentile(0.95).with_max_delay(Duration::from_millis(300));
+    let options = RequestQuoteOptions::new().with_hedging(hedge);
+
+    for i in 0..NUM_QUOTES {
+        let quote = client.request_quote_with_options_v2(order.clone(), options.clone()).await?;
+        println!("Quote {i}: found={}", quote.is_some());
+    }
+
+    println!("Latency stats: {:#?}", client.quote_latency_stats());
+    Ok(())
+}
@@ -15,7 +15,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Ethereum address: {:#x}", eth_address);
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
 
     // Get the wallet to see existing orders
     let wallet = renegade_client.get_wallet().await?;
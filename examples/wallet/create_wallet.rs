@@ -16,7 +16,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Creating Renegade wallet client for Arbitrum Sepolia...");
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
     println!("Wallet ID: {}", renegade_client.secrets.wallet_id);
     println!("\nCreating wallet in darkpool...");
 
@@ -20,7 +20,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Ethereum address: {:#x}", eth_address);
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
 
     // Deposit 1 WETH into the wallet
     let token_mint = WETH_ADDRESS;
@@ -15,7 +15,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Creating Renegade wallet client for Arbitrum Sepolia...");
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
     println!("Successfully created Renegade wallet!");
     println!("Wallet ID: {}", renegade_client.secrets.wallet_id);
     println!("Wallet generation complete!");
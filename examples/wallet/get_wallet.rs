@@ -17,7 +17,7 @@ async fn main() -> Result<()> {
     println!("Ethereum address: {:#x}", eth_address);
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
     println!("\nGetting wallet state from relayer...");
 
     // Get the wallet state from the relayer
@@ -27,7 +27,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Ethereum address: {:#x}", eth_address);
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
 
     // Create a sample order (selling WETH for USDC)
     let amount = 10_u128.pow(18); // 1 WETH
@@ -23,7 +23,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Ethereum address: {:#x}", eth_address);
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
 
     // Create a sample order (selling WETH for USDC)
     let amount = 10_u128.pow(18); // 1 WETH
@@ -40,7 +40,7 @@ async fn main() -> Result<(), eyre::Error> {
 
     // Await the task completion using the watch_task method
     match task_waiter.await {
-        Ok(()) => println!("Order placement task completed successfully!"),
+        Ok(task) => println!("Order placement task {} completed successfully!", task.id),
         Err(e) => println!("Order placement task failed: {e}"),
     }
 
@@ -20,7 +20,7 @@ async fn main() -> Result<(), eyre::Error> {
     println!("Ethereum address: {:#x}", eth_address);
 
     // Create the Renegade client for Arbitrum Sepolia
-    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key)?;
+    let renegade_client = RenegadeClient::new_arbitrum_sepolia(&private_key).await?;
 
     // Withdraw 0.5 WETH from the wallet
     let token_mint = WETH_ADDRESS;
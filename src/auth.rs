@@ -9,7 +9,11 @@ use hmac::Mac;
 use reqwest::header::{HeaderMap, HeaderValue};
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // -------------
 // | Constants |
@@ -35,9 +39,18 @@ const RENEGADE_HEADER_NAMESPACE: &str = "x-renegade";
 type HmacSha256 = hmac::Hmac<Sha256>;
 
 /// A type representing a symmetric HMAC key
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The key is zeroized on drop and redacted from `Debug` output. It is
+/// deliberately not `Copy`, so that copies of the key are explicit.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
 pub struct HmacKey(pub [u8; HMAC_KEY_LEN]);
 
+impl fmt::Debug for HmacKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HmacKey([REDACTED])")
+    }
+}
+
 #[cfg(feature = "darkpool-client")]
 impl From<HmacKey> for renegade_types_core::HmacKey {
     fn from(key: HmacKey) -> Self {
@@ -123,9 +136,12 @@ impl HmacKey {
         hmac.finalize().into_bytes().to_vec()
     }
 
-    /// Verify the HMAC of a message
+    /// Verify the HMAC of a message in constant time
     pub fn verify_mac(&self, msg: &[u8], mac: &[u8]) -> bool {
-        self.compute_mac(msg) == mac
+        let mut hmac =
+            HmacSha256::new_from_slice(self.inner()).expect("hmac can handle all slice lengths");
+        hmac.update(msg);
+        hmac.verify_slice(mac).is_ok()
     }
 }
 
@@ -0,0 +1,77 @@
+//! Admin commands
+
+use renegade_sdk::client::RenegadeClient;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    cli::{AdminCommand, GlobalArgs, PlaceInPoolArgs},
+    keys::admin_client,
+    output,
+};
+
+/// Run an admin command
+pub async fn run(global: &GlobalArgs, command: AdminCommand) -> eyre::Result<()> {
+    let client = admin_client(global).await?;
+
+    match command {
+        AdminCommand::OpenOrders(args) => {
+            let orders = match args.matching_pool {
+                Some(pool) => client.admin_get_open_orders_in_matching_pool(pool).await?,
+                None => client.admin_get_open_orders().await?,
+            };
+            output::print(global.output, &orders)
+        },
+        AdminCommand::AccountOrders(args) => {
+            let orders = client.admin_get_account_orders(args.account_id).await?;
+            output::print(global.output, &orders)
+        },
+        AdminCommand::Order(args) => {
+            output::print(global.output, &client.admin_get_order(args.order_id).await?)
+        },
+        AdminCommand::TaskQueuePaused(args) => {
+            let paused = client.admin_is_task_queue_paused(args.account_id).await?;
+            let result = json!({ "account_id": args.account_id, "paused": paused });
+            output::print(global.output, &result)
+        },
+        AdminCommand::CreatePool(args) => {
+            client.admin_create_matching_pool(args.matching_pool.clone()).await?;
+            output::print(global.output, &json!({ "matching_pool": args.matching_pool }))
+        },
+        AdminCommand::AssignPool(args) => {
+            client.admin_assign_order_to_pool(args.order_id, args.matching_pool.clone()).await?;
+            let result = json!({ "order_id": args.order_id, "matching_pool": args.matching_pool });
+            output::print(global.output, &result)
+        },
+        AdminCommand::PlaceInPool(args) => {
+            let order_id = place_order_in_pool(&client, args).await?;
+            output::print(global.output, &json!({ "order_id": order_id }))
+        },
+    }
+}
+
+/// Build and place an order in a matching pool, returning its ID
+async fn place_order_in_pool(client: &RenegadeClient, args: PlaceInPoolArgs) -> eyre::Result<Uuid> {
+    let PlaceInPoolArgs { order: args, matching_pool } = args;
+    let mut builder = client
+        .new_admin_order_builder()
+        .with_input_mint(&args.input_mint)?
+        .with_output_mint(&args.output_mint)?
+        .with_input_amount(args.amount_in)
+        .with_order_type(args.order_type.into())
+        .with_allow_external_matches(!args.no_external_matches)
+        .with_matching_pool(matching_pool);
+
+    if let Some(amount) = args.min_output_amount {
+        builder = builder.with_min_output_amount(amount);
+    }
+    if let Some(min_fill_size) = args.min_fill_size {
+        builder = builder.with_min_fill_size(min_fill_size);
+    }
+
+    let order = builder.build()?;
+    let order_id = order.order.id;
+    client.admin_place_order_in_pool(order).await?;
+
+    Ok(order_id)
+}
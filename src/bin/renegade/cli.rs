@@ -0,0 +1,322 @@
+//! Command line argument definitions
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use renegade_external_api::types::OrderType;
+use renegade_sdk::Network;
+use uuid::Uuid;
+
+use crate::output::OutputFormat;
+
+/// A command line tool for the Renegade relayer
+#[derive(Debug, Parser)]
+#[command(name = "renegade", version, about)]
+pub struct Cli {
+    /// The global options
+    #[command(flatten)]
+    pub global: GlobalArgs,
+    /// The command to run
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Options shared by all commands
+#[derive(Debug, Args)]
+pub struct GlobalArgs {
+    /// The network to connect to: arbitrum-one, arbitrum-sepolia,
+    /// base-mainnet, base-sepolia or ethereum-sepolia
+    #[arg(long, global = true, env = "RENEGADE_NETWORK", default_value = "arbitrum-sepolia")]
+    pub network: Network,
+    /// The output format
+    #[arg(long, short, global = true, value_enum, default_value = "table")]
+    pub output: OutputFormat,
+    /// The key options
+    #[command(flatten)]
+    pub keys: KeyArgs,
+}
+
+/// Options for loading keys and credentials
+#[derive(Debug, Args)]
+pub struct KeyArgs {
+    /// The hex encoded private key of the account
+    #[arg(long, global = true, env = "PKEY", hide_env_values = true)]
+    pub private_key: Option<String>,
+    /// The path to an encrypted JSON keystore holding the account's private
+    /// key. Takes precedence over `--private-key`
+    #[arg(long, global = true, env = "RENEGADE_KEYSTORE")]
+    pub keystore: Option<PathBuf>,
+    /// The password used to decrypt the keystore
+    #[arg(long, global = true, env = "RENEGADE_KEYSTORE_PASSWORD", hide_env_values = true)]
+    pub keystore_password: Option<String>,
+    /// The index of the account to derive from the private key
+    #[arg(long, global = true, env = "RENEGADE_ACCOUNT_INDEX", default_value_t = 0)]
+    pub account_index: u32,
+    /// The external match API key
+    #[arg(long, global = true, env = "EXTERNAL_MATCH_KEY", hide_env_values = true)]
+    pub api_key: Option<String>,
+    /// The external match API secret
+    #[arg(long, global = true, env = "EXTERNAL_MATCH_SECRET", hide_env_values = true)]
+    pub api_secret: Option<String>,
+    /// The base64 encoded admin HMAC key, required for admin commands
+    #[arg(long, global = true, env = "RENEGADE_ADMIN_KEY", hide_env_values = true)]
+    pub admin_key: Option<String>,
+    /// The RPC URL used to submit transactions
+    #[arg(long, global = true, env = "RPC_URL")]
+    pub rpc_url: Option<String>,
+}
+
+// ------------
+// | Commands |
+// ------------
+
+/// The top level commands
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List tradable markets
+    Markets,
+    /// Get the market depth for one token, or for all pairs
+    Depth(DepthArgs),
+    /// Get metadata about the exchange
+    Metadata,
+    /// Request a quote for an external match
+    Quote(ExternalOrderArgs),
+    /// Assemble a quote, read from a JSON file, into a match bundle
+    Assemble(AssembleArgs),
+    /// Request a quote, assemble it, and submit the settlement transaction
+    Execute(ExecuteArgs),
+    /// Manage a darkpool account
+    #[command(subcommand)]
+    Wallet(WalletCommand),
+    /// Run admin actions against the relayer
+    #[command(subcommand)]
+    Admin(AdminCommand),
+}
+
+/// Arguments for the `depth` command
+#[derive(Debug, Args)]
+pub struct DepthArgs {
+    /// The token to get the depth for; omit for all pairs
+    pub mint: Option<String>,
+}
+
+/// Arguments describing an external order
+#[derive(Debug, Args)]
+pub struct ExternalOrderArgs {
+    /// The mint of the token to sell
+    #[arg(long)]
+    pub input_mint: String,
+    /// The mint of the token to buy
+    #[arg(long)]
+    pub output_mint: String,
+    /// The amount of the input token to sell
+    #[arg(long, required_unless_present = "output_amount", conflicts_with = "output_amount")]
+    pub input_amount: Option<u128>,
+    /// The amount of the output token to buy
+    #[arg(long)]
+    pub output_amount: Option<u128>,
+    /// Treat the output amount as exact, net of fees
+    #[arg(long, requires = "output_amount")]
+    pub exact_output: bool,
+    /// The minimum fill size
+    #[arg(long)]
+    pub min_fill_size: Option<u128>,
+}
+
+/// Arguments for the `assemble` command
+#[derive(Debug, Args)]
+pub struct AssembleArgs {
+    /// The path to a JSON file holding a quote, as output by `quote -o json`
+    pub quote_file: PathBuf,
+}
+
+/// Arguments for the `execute` command
+#[derive(Debug, Args)]
+pub struct ExecuteArgs {
+    /// The order to execute
+    #[command(flatten)]
+    pub order: ExternalOrderArgs,
+    /// Settle the match for this input amount, within the bundle's bounds
+    #[arg(long)]
+    pub settle_input_amount: Option<u128>,
+}
+
+// -------------------
+// | Wallet Commands |
+// -------------------
+
+/// Commands that act on a darkpool account
+#[derive(Debug, Subcommand)]
+pub enum WalletCommand {
+    /// Register the account with the relayer
+    Create,
+    /// Sync the account with onchain state
+    Sync,
+    /// List the account's balances
+    Balances,
+    /// List the account's orders
+    Orders(HistoricArgs),
+    /// Deposit funds into the account
+    Deposit(DepositArgs),
+    /// Withdraw funds from the account
+    Withdraw(TransferArgs),
+    /// Place an order
+    Place(PlaceOrderArgs),
+    /// Cancel an order
+    Cancel(OrderIdArgs),
+    /// List the account's tasks
+    Tasks(HistoricArgs),
+}
+
+/// Arguments for listing orders or tasks
+#[derive(Debug, Args)]
+pub struct HistoricArgs {
+    /// Include historic (inactive) entries
+    #[arg(long)]
+    pub historic: bool,
+}
+
+/// Arguments for a deposit or withdrawal
+#[derive(Debug, Args)]
+pub struct TransferArgs {
+    /// The mint of the token to transfer
+    #[arg(long)]
+    pub mint: String,
+    /// The amount to transfer
+    #[arg(long)]
+    pub amount: u128,
+}
+
+/// Arguments for a deposit
+#[derive(Debug, Args)]
+pub struct DepositArgs {
+    /// The token and amount to deposit
+    #[command(flatten)]
+    pub transfer: TransferArgs,
+    /// Approve Permit2 to spend the deposit amount before depositing. Requires
+    /// an RPC URL
+    #[arg(long)]
+    pub approve: bool,
+}
+
+/// Arguments identifying an order
+#[derive(Debug, Args)]
+pub struct OrderIdArgs {
+    /// The ID of the order
+    pub order_id: Uuid,
+}
+
+/// Arguments describing an order to place
+#[derive(Debug, Args)]
+pub struct PlaceOrderArgs {
+    /// The mint of the token to sell
+    #[arg(long)]
+    pub input_mint: String,
+    /// The mint of the token to buy
+    #[arg(long)]
+    pub output_mint: String,
+    /// The amount of the input token to sell
+    #[arg(long)]
+    pub amount_in: u128,
+    /// The minimum amount of the output token to receive
+    #[arg(long)]
+    pub min_output_amount: Option<u128>,
+    /// The minimum fill size
+    #[arg(long)]
+    pub min_fill_size: Option<u128>,
+    /// The order type
+    #[arg(long, value_enum, default_value = "public")]
+    pub order_type: OrderTypeArg,
+    /// Disallow external matches on the order
+    #[arg(long)]
+    pub no_external_matches: bool,
+}
+
+/// The order types that can be placed
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum OrderTypeArg {
+    /// A public order
+    Public,
+    /// A privately settled order
+    NativelySettledPrivate,
+    /// A Renegade settled order with public fills
+    RenegadeSettledPublicFill,
+    /// A Renegade settled order with private fills
+    RenegadeSettledPrivateFill,
+}
+
+impl From<OrderTypeArg> for OrderType {
+    fn from(arg: OrderTypeArg) -> Self {
+        match arg {
+            OrderTypeArg::Public => OrderType::PublicOrder,
+            OrderTypeArg::NativelySettledPrivate => OrderType::NativelySettledPrivateOrder,
+            OrderTypeArg::RenegadeSettledPublicFill => OrderType::RenegadeSettledPublicFillOrder,
+            OrderTypeArg::RenegadeSettledPrivateFill => OrderType::RenegadeSettledPrivateFillOrder,
+        }
+    }
+}
+
+// ------------------
+// | Admin Commands |
+// ------------------
+
+/// Admin commands, which require an admin HMAC key
+#[derive(Debug, Subcommand)]
+pub enum AdminCommand {
+    /// List all open orders, optionally in a single matching pool
+    OpenOrders(MatchingPoolArgs),
+    /// List all orders for an account
+    AccountOrders(AccountIdArgs),
+    /// Look up an order by its ID
+    Order(OrderIdArgs),
+    /// Check whether an account's task queue is paused
+    TaskQueuePaused(AccountIdArgs),
+    /// Create a matching pool
+    CreatePool(PoolNameArgs),
+    /// Assign an order to a matching pool
+    AssignPool(AssignPoolArgs),
+    /// Place an order for the admin account in a matching pool
+    PlaceInPool(PlaceInPoolArgs),
+}
+
+/// Arguments optionally naming a matching pool
+#[derive(Debug, Args)]
+pub struct MatchingPoolArgs {
+    /// The matching pool to filter by
+    #[arg(long)]
+    pub matching_pool: Option<String>,
+}
+
+/// Arguments identifying an account
+#[derive(Debug, Args)]
+pub struct AccountIdArgs {
+    /// The ID of the account
+    pub account_id: Uuid,
+}
+
+/// Arguments naming a matching pool
+#[derive(Debug, Args)]
+pub struct PoolNameArgs {
+    /// The name of the matching pool
+    pub matching_pool: String,
+}
+
+/// Arguments for assigning an order to a matching pool
+#[derive(Debug, Args)]
+pub struct AssignPoolArgs {
+    /// The ID of the order
+    pub order_id: Uuid,
+    /// The name of the matching pool
+    pub matching_pool: String,
+}
+
+/// Arguments for placing an order in a matching pool
+#[derive(Debug, Args)]
+pub struct PlaceInPoolArgs {
+    /// The order to place
+    #[command(flatten)]
+    pub order: PlaceOrderArgs,
+    /// The matching pool to place the order in
+    #[arg(long)]
+    pub matching_pool: String,
+}
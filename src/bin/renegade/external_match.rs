@@ -0,0 +1,107 @@
+//! External match commands
+
+use std::fs;
+
+use eyre::eyre;
+use renegade_sdk::{
+    ExternalOrderBuilderV2,
+    api_types::{ExternalOrderV2, SignedExternalQuoteV2},
+};
+use serde_json::json;
+
+use crate::{
+    cli::{AssembleArgs, DepthArgs, ExecuteArgs, ExternalOrderArgs, GlobalArgs},
+    keys::{external_match_client, wallet},
+    output,
+};
+
+/// List tradable markets
+pub async fn markets(global: &GlobalArgs) -> eyre::Result<()> {
+    let client = external_match_client(global)?;
+    let markets = client.get_markets().await?;
+    output::print(global.output, &markets)
+}
+
+/// Get the market depth for one token, or for all pairs
+pub async fn depth(global: &GlobalArgs, args: DepthArgs) -> eyre::Result<()> {
+    let client = external_match_client(global)?;
+    match args.mint {
+        Some(mint) => output::print(global.output, &client.get_market_depth(&mint).await?),
+        None => output::print(global.output, &client.get_market_depths_all_pairs().await?),
+    }
+}
+
+/// Get metadata about the exchange
+pub async fn metadata(global: &GlobalArgs) -> eyre::Result<()> {
+    let client = external_match_client(global)?;
+    let metadata = client.get_exchange_metadata().await?;
+    output::print(global.output, &metadata)
+}
+
+/// Request a quote for an external match
+pub async fn quote(global: &GlobalArgs, args: ExternalOrderArgs) -> eyre::Result<()> {
+    let client = external_match_client(global)?;
+    let order = build_order(args)?;
+    let quote = client.request_quote_v2(order).await?.ok_or_else(|| eyre!("no quote found"))?;
+    output::print(global.output, &quote)
+}
+
+/// Assemble a quote into a match bundle
+pub async fn assemble(global: &GlobalArgs, args: AssembleArgs) -> eyre::Result<()> {
+    let client = external_match_client(global)?;
+    let quote_json = fs::read_to_string(&args.quote_file)?;
+    let quote: SignedExternalQuoteV2 = serde_json::from_str(&quote_json)?;
+
+    let bundle =
+        client.assemble_quote_v2(quote).await?.ok_or_else(|| eyre!("no match bundle found"))?;
+    output::print(global.output, &bundle)
+}
+
+/// Request a quote, assemble it, and submit the settlement transaction
+pub async fn execute(global: &GlobalArgs, args: ExecuteArgs) -> eyre::Result<()> {
+    let client = external_match_client(global)?;
+    let wallet = wallet(&global.keys)?;
+
+    let order = build_order(args.order)?;
+    let quote = client.request_quote_v2(order).await?.ok_or_else(|| eyre!("no quote found"))?;
+    let mut bundle =
+        client.assemble_quote_v2(quote).await?.ok_or_else(|| eyre!("no match bundle found"))?;
+
+    if let Some(input_amount) = args.settle_input_amount {
+        bundle.set_input_amount(input_amount)?;
+    }
+
+    let receipt = wallet.send_transaction(bundle.settlement_tx()).await?.get_receipt().await?;
+    let result = json!({
+        "tx_hash": format!("{:#x}", receipt.transaction_hash),
+        "success": receipt.status(),
+        "send_amount": bundle.send_amount().to_string(),
+        "receive_amount": bundle.receive_amount().to_string(),
+    });
+    output::print(global.output, &result)
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Build an external order from the command line arguments
+fn build_order(args: ExternalOrderArgs) -> eyre::Result<ExternalOrderV2> {
+    let mut builder =
+        ExternalOrderBuilderV2::new().input_mint(&args.input_mint).output_mint(&args.output_mint);
+
+    if let Some(amount) = args.input_amount {
+        builder = builder.input_amount(amount);
+    }
+    if let Some(amount) = args.output_amount {
+        builder = builder.output_amount(amount);
+    }
+    if args.exact_output {
+        builder = builder.use_exact_output_amount();
+    }
+    if let Some(min_fill_size) = args.min_fill_size {
+        builder = builder.min_fill_size(min_fill_size);
+    }
+
+    Ok(builder.build()?)
+}
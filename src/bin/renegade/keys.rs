@@ -0,0 +1,81 @@
+//! Loading keys and constructing SDK clients from command line options
+
+use std::sync::Arc;
+
+use alloy::{
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use eyre::eyre;
+use renegade_sdk::{
+    ExternalMatchClient, HmacKey, KeySource, client::RenegadeClient, config::RenegadeClientConfig,
+};
+use url::Url;
+use zeroize::Zeroizing;
+
+use crate::cli::{GlobalArgs, KeyArgs};
+
+/// A provider used to submit transactions
+pub type Wallet = Arc<dyn Provider>;
+
+/// Load the account's private key, from a keystore if one is given, otherwise
+/// from the raw private key option
+pub fn load_signer(keys: &KeyArgs) -> eyre::Result<PrivateKeySigner> {
+    let source = match (&keys.keystore, &keys.private_key) {
+        (Some(path), _) => {
+            let password = keys.keystore_password.clone().map(Zeroizing::new).ok_or_else(|| {
+                eyre!("a keystore password is required; set RENEGADE_KEYSTORE_PASSWORD")
+            })?;
+            KeySource::Keystore { path: path.clone(), password }
+        },
+        (None, Some(key)) => KeySource::PrivateKey(Zeroizing::new(key.clone())),
+        (None, None) => eyre::bail!("a private key is required; set PKEY or pass --keystore"),
+    };
+
+    source.load().map_err(|e| eyre!("failed to load private key: {e}"))
+}
+
+/// Build an external match client for the configured network
+pub fn external_match_client(global: &GlobalArgs) -> eyre::Result<ExternalMatchClient> {
+    let keys = &global.keys;
+    let key = keys.api_key.as_deref().ok_or_else(|| eyre!("EXTERNAL_MATCH_KEY is not set"))?;
+    let secret =
+        keys.api_secret.as_deref().ok_or_else(|| eyre!("EXTERNAL_MATCH_SECRET is not set"))?;
+
+    Ok(ExternalMatchClient::from_network(key, secret, global.network)?)
+}
+
+/// Build a wallet client for the configured network
+pub async fn renegade_client(global: &GlobalArgs) -> eyre::Result<RenegadeClient> {
+    let key = load_signer(&global.keys)?;
+    let config = RenegadeClientConfig::from_network(global.network, &key)
+        .with_account_index(global.keys.account_index);
+
+    Ok(RenegadeClient::new(config).await?)
+}
+
+/// Build a wallet client configured with the admin HMAC key
+pub async fn admin_client(global: &GlobalArgs) -> eyre::Result<RenegadeClient> {
+    let key = load_signer(&global.keys)?;
+    let admin_key = global
+        .keys
+        .admin_key
+        .as_deref()
+        .ok_or_else(|| eyre!("an admin key is required; set RENEGADE_ADMIN_KEY"))?;
+    let admin_key = HmacKey::from_base64_string(admin_key).map_err(|e| eyre!(e))?;
+
+    let config = RenegadeClientConfig::from_network_admin(global.network, &key, admin_key)
+        .with_account_index(global.keys.account_index);
+
+    Ok(RenegadeClient::new(config).await?)
+}
+
+/// Build a provider which signs transactions with the account's private key
+pub fn wallet(keys: &KeyArgs) -> eyre::Result<Wallet> {
+    let rpc_url = keys.rpc_url.as_deref().ok_or_else(|| eyre!("RPC_URL is not set"))?;
+    let url = Url::parse(rpc_url)?;
+    let signer = load_signer(keys)?;
+    let provider = ProviderBuilder::new().wallet(signer).connect_http(url);
+
+    Ok(Arc::new(provider))
+}
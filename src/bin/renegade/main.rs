@@ -0,0 +1,32 @@
+//! A command line tool for the Renegade relayer, built on the SDK's external
+//! match and wallet clients
+
+#![deny(missing_docs)]
+#![deny(clippy::missing_docs_in_private_items)]
+
+use clap::Parser;
+
+use crate::cli::{Cli, Command};
+
+mod admin;
+mod cli;
+mod external_match;
+mod keys;
+mod output;
+mod wallet;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let Cli { global, command } = Cli::parse();
+
+    match command {
+        Command::Markets => external_match::markets(&global).await,
+        Command::Depth(args) => external_match::depth(&global, args).await,
+        Command::Metadata => external_match::metadata(&global).await,
+        Command::Quote(args) => external_match::quote(&global, args).await,
+        Command::Assemble(args) => external_match::assemble(&global, args).await,
+        Command::Execute(args) => external_match::execute(&global, args).await,
+        Command::Wallet(command) => wallet::run(&global, command).await,
+        Command::Admin(command) => admin::run(&global, command).await,
+    }
+}
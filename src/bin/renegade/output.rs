@@ -0,0 +1,155 @@
+//! Output formatting for command results
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// The separator between nested field names in table columns
+const FIELD_SEPARATOR: &str = ".";
+/// The separator between table columns
+const COLUMN_SEPARATOR: &str = "  ";
+
+/// The format in which to print command results
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum OutputFormat {
+    /// Pretty printed JSON
+    Json,
+    /// A human readable table
+    Table,
+}
+
+/// Print a command result in the given format
+pub fn print<T: Serialize>(format: OutputFormat, value: &T) -> eyre::Result<()> {
+    let value = serde_json::to_value(value)?;
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&value)?),
+        OutputFormat::Table => println!("{}", render_table(&value)),
+    }
+
+    Ok(())
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Render a JSON value as a table
+///
+/// Arrays of objects render as one row per element, objects render as
+/// field/value pairs, and scalars render as-is. An object wrapping a single
+/// array, e.g. `{ "markets": [...] }`, renders as the inner array.
+fn render_table(value: &Value) -> String {
+    match value {
+        Value::Array(rows) => render_rows(rows),
+        Value::Object(fields) => match single_array_field(fields) {
+            Some(rows) => render_rows(rows),
+            None => render_fields(fields),
+        },
+        scalar => render_cell(scalar),
+    }
+}
+
+/// Render an array of values, one row per element
+fn render_rows(rows: &[Value]) -> String {
+    if rows.is_empty() {
+        return "(none)".to_string();
+    }
+
+    // Flatten each row and collect columns in order of first appearance
+    let flattened: Vec<Vec<(String, String)>> = rows.iter().map(flatten).collect();
+    let mut columns: Vec<String> = Vec::new();
+    for (column, _) in flattened.iter().flatten() {
+        if !columns.contains(column) {
+            columns.push(column.clone());
+        }
+    }
+
+    let cells: Vec<Vec<String>> = flattened
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|col| {
+                    row.iter().find(|(c, _)| c == col).map(|(_, v)| v.clone()).unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect();
+
+    render_grid(&columns, &cells)
+}
+
+/// Render an object as field/value pairs
+fn render_fields(fields: &Map<String, Value>) -> String {
+    let rows: Vec<Vec<String>> = flatten(&Value::Object(fields.clone()))
+        .into_iter()
+        .map(|(field, value)| vec![field, value])
+        .collect();
+
+    render_grid(&["field".to_string(), "value".to_string()], &rows)
+}
+
+/// Render a header and rows as aligned columns
+fn render_grid(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(String::len).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let render_line = |cells: &[String]| {
+        let padded: Vec<String> =
+            cells.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+        padded.join(COLUMN_SEPARATOR).trim_end().to_string()
+    };
+
+    let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    let mut lines = vec![render_line(header), render_line(&rule)];
+    lines.extend(rows.iter().map(|row| render_line(row)));
+    lines.join("\n")
+}
+
+/// Flatten a value into `(column, cell)` pairs, joining nested object fields
+/// with a separator
+fn flatten(value: &Value) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    flatten_into("", value, &mut out);
+    out
+}
+
+/// Recursively flatten a value into the given output
+fn flatten_into(prefix: &str, value: &Value, out: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(fields) if !fields.is_empty() => {
+            for (key, value) in fields {
+                let column = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}{FIELD_SEPARATOR}{key}")
+                };
+                flatten_into(&column, value, out);
+            }
+        },
+        value => out.push((prefix.to_string(), render_cell(value))),
+    }
+}
+
+/// Render a single value as a table cell
+fn render_cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Get the inner array of an object with exactly one field, if that field is
+/// an array
+fn single_array_field(fields: &Map<String, Value>) -> Option<&[Value]> {
+    if fields.len() != 1 {
+        return None;
+    }
+
+    fields.values().next().and_then(Value::as_array).map(Vec::as_slice)
+}
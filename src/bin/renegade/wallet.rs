@@ -0,0 +1,126 @@
+//! Wallet commands
+
+use std::{pin::pin, str::FromStr};
+
+use alloy::primitives::{Address, U256};
+use futures_util::StreamExt;
+use renegade_external_api::types::ApiTask;
+use renegade_sdk::{client::RenegadeClient, websocket::TaskWaiter};
+use serde_json::json;
+
+use crate::{
+    cli::{GlobalArgs, PlaceOrderArgs, WalletCommand},
+    keys::{renegade_client, wallet},
+    output,
+};
+
+/// Run a wallet command
+pub async fn run(global: &GlobalArgs, command: WalletCommand) -> eyre::Result<()> {
+    let client = renegade_client(global).await?;
+    let account_id = client.get_account_id();
+
+    match command {
+        WalletCommand::Create => {
+            client.create_account().await?;
+            output::print(global.output, &json!({ "account_id": account_id }))
+        },
+        WalletCommand::Sync => {
+            client.sync_account().await?;
+            output::print(global.output, &json!({ "account_id": account_id, "synced": true }))
+        },
+        WalletCommand::Balances => output::print(global.output, &client.get_balances().await?),
+        WalletCommand::Orders(args) => {
+            output::print(global.output, &client.get_orders(args.historic).await?)
+        },
+        WalletCommand::Deposit(args) => {
+            let mint = Address::from_str(&args.transfer.mint)?;
+            let amount = args.transfer.amount;
+            if args.approve {
+                approve_permit2(global, &client, mint, amount).await?;
+            }
+
+            wait_for_task(client.enqueue_deposit(mint, amount).await?).await?;
+            output::print(global.output, &transfer_result(mint, amount))
+        },
+        WalletCommand::Withdraw(args) => {
+            let mint = Address::from_str(&args.mint)?;
+            wait_for_task(client.enqueue_withdrawal(mint, args.amount).await?).await?;
+            output::print(global.output, &transfer_result(mint, args.amount))
+        },
+        WalletCommand::Place(args) => {
+            let order_id = place_order(&client, args).await?;
+            output::print(global.output, &json!({ "order_id": order_id }))
+        },
+        WalletCommand::Cancel(args) => {
+            wait_for_task(client.enqueue_order_cancellation(args.order_id).await?).await?;
+            output::print(global.output, &json!({ "order_id": args.order_id, "cancelled": true }))
+        },
+        WalletCommand::Tasks(args) => {
+            output::print(global.output, &client.get_tasks(args.historic).await?)
+        },
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Build and place an order, returning its ID
+async fn place_order(client: &RenegadeClient, args: PlaceOrderArgs) -> eyre::Result<uuid::Uuid> {
+    let mut builder = client
+        .new_order_builder()
+        .with_input_mint(&args.input_mint)?
+        .with_output_mint(&args.output_mint)?
+        .with_input_amount(args.amount_in)
+        .with_order_type(args.order_type.into())
+        .with_allow_external_matches(!args.no_external_matches);
+
+    if let Some(amount) = args.min_output_amount {
+        builder = builder.with_min_output_amount(amount);
+    }
+    if let Some(min_fill_size) = args.min_fill_size {
+        builder = builder.with_min_fill_size(min_fill_size);
+    }
+
+    let order = builder.build()?;
+    let order_id = order.order.id;
+    wait_for_task(client.enqueue_order_placement(order).await?).await?;
+
+    Ok(order_id)
+}
+
+/// Wait for a task to complete, reporting its intermediate states on stderr
+async fn wait_for_task(mut waiter: TaskWaiter) -> eyre::Result<ApiTask> {
+    let task_id = waiter.task_id();
+    let mut progress = pin!(waiter.progress());
+    loop {
+        tokio::select! {
+            biased;
+            Some(state) = progress.next() => eprintln!("task {task_id}: {state}"),
+            task = &mut waiter => return Ok(task?),
+        }
+    }
+}
+
+/// Approve Permit2 to spend the given amount of a token, waiting for the
+/// approval to be mined
+async fn approve_permit2(
+    global: &GlobalArgs,
+    client: &RenegadeClient,
+    mint: Address,
+    amount: u128,
+) -> eyre::Result<()> {
+    let wallet = wallet(&global.keys)?;
+    let tx = client.build_erc20_approval_tx(mint, U256::from(amount));
+    let receipt = wallet.send_transaction(tx).await?.get_receipt().await?;
+    if !receipt.status() {
+        eyre::bail!("approval transaction {:#x} reverted", receipt.transaction_hash);
+    }
+
+    Ok(())
+}
+
+/// The result of a deposit or withdrawal
+fn transfer_result(mint: Address, amount: u128) -> serde_json::Value {
+    json!({ "mint": format!("{mint:#x}"), "amount": amount.to_string() })
+}
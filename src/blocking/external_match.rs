@@ -0,0 +1,296 @@
+//! A blocking facade over the external match client
+
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::{
+    AssembleQuoteOptions, AssembleQuoteOptionsV2, ExternalMatchClientError, ExternalMatchOptions,
+    NetworkConfig, QuoteLatencyStats, RequestQuoteOptions,
+    api_types::{
+        ExternalMatchResponseV2, ExternalOrderV2, GetMarketDepthByMintResponse,
+        GetMarketDepthsResponse, GetMarketsResponse, SignedExternalQuoteV2,
+        exchange_metadata::ExchangeMetadataResponse,
+        v1_types::{
+            ExternalMatchResponse, ExternalOrder, GetDepthByMintResponse,
+            GetDepthForAllPairsResponse, GetSupportedTokensResponse, GetTokenPricesResponse,
+            SignedExternalQuote,
+        },
+    },
+};
+
+use super::{blocking_methods, build_runtime};
+
+/// The async client wrapped by the blocking client
+type AsyncExternalMatchClient = crate::ExternalMatchClient;
+
+/// A blocking client for requesting external matches from the relayer
+///
+/// Mirrors the async [`ExternalMatchClient`](crate::ExternalMatchClient)
+#[derive(Clone, Debug)]
+pub struct ExternalMatchClient {
+    /// The async client
+    inner: AsyncExternalMatchClient,
+    /// The runtime used to drive the async client
+    runtime: Arc<Runtime>,
+}
+
+impl ExternalMatchClient {
+    // ----------------
+    // | Constructors |
+    // ----------------
+
+    /// Wrap an async client in a blocking client
+    pub fn from_async(inner: AsyncExternalMatchClient) -> Result<Self, ExternalMatchClientError> {
+        let runtime = build_runtime().map_err(ExternalMatchClientError::runtime)?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Create a new client
+    pub fn new(
+        api_key: &str,
+        api_secret: &str,
+        auth_base_url: &str,
+        relayer_base_url: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new(
+            api_key,
+            api_secret,
+            auth_base_url,
+            relayer_base_url,
+        )?)
+    }
+
+    /// Create a new client with a custom HTTP client
+    pub fn new_with_client(
+        api_key: &str,
+        api_secret: &str,
+        auth_base_url: &str,
+        relayer_base_url: &str,
+        client: reqwest::Client,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new_with_client(
+            api_key,
+            api_secret,
+            auth_base_url,
+            relayer_base_url,
+            client,
+        )?)
+    }
+
+    /// Create a new client for the given network
+    pub fn from_network(
+        api_key: &str,
+        api_secret: &str,
+        network: impl Into<NetworkConfig>,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::from_network(api_key, api_secret, network)?)
+    }
+
+    /// Create a new client for the Ethereum Sepolia network
+    pub fn new_ethereum_sepolia_client(
+        api_key: &str,
+        api_secret: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new_ethereum_sepolia_client(
+            api_key, api_secret,
+        )?)
+    }
+
+    /// Create a new client for the Arbitrum Sepolia network
+    pub fn new_arbitrum_sepolia_client(
+        api_key: &str,
+        api_secret: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new_arbitrum_sepolia_client(
+            api_key, api_secret,
+        )?)
+    }
+
+    /// Create a new client for the Base Sepolia network
+    pub fn new_base_sepolia_client(
+        api_key: &str,
+        api_secret: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new_base_sepolia_client(api_key, api_secret)?)
+    }
+
+    /// Create a new client for the Arbitrum One network
+    pub fn new_arbitrum_one_client(
+        api_key: &str,
+        api_secret: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new_arbitrum_one_client(api_key, api_secret)?)
+    }
+
+    /// Create a new client for the Base mainnet network
+    pub fn new_base_mainnet_client(
+        api_key: &str,
+        api_secret: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_async(AsyncExternalMatchClient::new_base_mainnet_client(api_key, api_secret)?)
+    }
+
+    /// Get a reference to the underlying async client
+    pub fn as_async(&self) -> &AsyncExternalMatchClient {
+        &self.inner
+    }
+
+    /// Get a snapshot of the client's quote latency statistics
+    pub fn quote_latency_stats(&self) -> QuoteLatencyStats {
+        self.inner.quote_latency_stats()
+    }
+
+    // ---------------
+    // | V2 Requests |
+    // ---------------
+
+    blocking_methods! {
+        /// Get a list of tradable markets. Includes the tokens pair, current
+        /// price, and fee rates for each market.
+        fn get_markets(&self) -> Result<GetMarketsResponse, ExternalMatchClientError>;
+
+        /// Get the market depth for the given token.
+        ///
+        /// The address is the address of the token
+        fn get_market_depth(
+            &self,
+            address: &str,
+        ) -> Result<GetMarketDepthByMintResponse, ExternalMatchClientError>;
+
+        /// Get the market depths for all supported pairs
+        fn get_market_depths_all_pairs(
+            &self,
+        ) -> Result<GetMarketDepthsResponse, ExternalMatchClientError>;
+
+        /// Request a quote for an external match (v2 API)
+        fn request_quote_v2(
+            &self,
+            order: ExternalOrderV2,
+        ) -> Result<Option<SignedExternalQuoteV2>, ExternalMatchClientError>;
+
+        /// Request a quote for an external match, with options (v2 API)
+        fn request_quote_with_options_v2(
+            &self,
+            order: ExternalOrderV2,
+            options: RequestQuoteOptions,
+        ) -> Result<Option<SignedExternalQuoteV2>, ExternalMatchClientError>;
+
+        /// Assemble a quote into a match bundle, ready for settlement (v2 API)
+        fn assemble_quote_v2(
+            &self,
+            quote: SignedExternalQuoteV2,
+        ) -> Result<Option<ExternalMatchResponseV2>, ExternalMatchClientError>;
+
+        /// Assemble a quote into a match bundle, ready for settlement, with
+        /// options (v2 API)
+        fn assemble_quote_with_options_v2(
+            &self,
+            quote: SignedExternalQuoteV2,
+            options: AssembleQuoteOptionsV2,
+        ) -> Result<Option<ExternalMatchResponseV2>, ExternalMatchClientError>;
+
+        /// Request an external match (v2 API)
+        fn request_external_match_v2(
+            &self,
+            order: ExternalOrderV2,
+        ) -> Result<Option<ExternalMatchResponseV2>, ExternalMatchClientError>;
+
+        /// Request an external match and specify any options for the request
+        /// (v2 API)
+        fn request_external_match_with_options_v2(
+            &self,
+            order: ExternalOrderV2,
+            options: ExternalMatchOptions,
+        ) -> Result<Option<ExternalMatchResponseV2>, ExternalMatchClientError>;
+
+        /// Get metadata about the Renegade exchange
+        fn get_exchange_metadata(
+            &self,
+        ) -> Result<ExchangeMetadataResponse, ExternalMatchClientError>;
+    }
+
+    // ---------------
+    // | V1 Requests |
+    // ---------------
+
+    blocking_methods! {
+        /// Get a list of supported tokens for external matches
+        #[deprecated(
+            since = "2.0.0",
+            note = "Use get_markets instead, which returns all supported tokens along with their current price"
+        )]
+        fn get_supported_tokens(
+            &self,
+        ) -> Result<GetSupportedTokensResponse, ExternalMatchClientError>;
+
+        /// Get token prices for all supported tokens
+        #[deprecated(
+            since = "2.0.0",
+            note = "Use get_markets instead, which returns all supported tokens along with their current price"
+        )]
+        fn get_token_prices(&self) -> Result<GetTokenPricesResponse, ExternalMatchClientError>;
+
+        /// Get the order book depth for a token
+        ///
+        /// The address is the address of the token
+        #[deprecated(since = "2.0.0", note = "Use get_market_depth instead")]
+        fn get_order_book_depth(
+            &self,
+            address: &str,
+        ) -> Result<GetDepthByMintResponse, ExternalMatchClientError>;
+
+        /// Get the order book depth for all supported tokens
+        #[deprecated(since = "2.0.0", note = "Use get_market_depths_all_pairs instead")]
+        fn get_order_book_depth_all_pairs(
+            &self,
+        ) -> Result<GetDepthForAllPairsResponse, ExternalMatchClientError>;
+
+        /// Request a quote for an external match (v1 API)
+        fn request_quote(
+            &self,
+            order: ExternalOrder,
+        ) -> Result<Option<SignedExternalQuote>, ExternalMatchClientError>;
+
+        /// Request a quote for an external match, with options (v1 API)
+        fn request_quote_with_options(
+            &self,
+            order: ExternalOrder,
+            options: RequestQuoteOptions,
+        ) -> Result<Option<SignedExternalQuote>, ExternalMatchClientError>;
+
+        /// Assemble a quote into a match bundle, ready for settlement (v1 API)
+        ///
+        /// Returns a non-malleable `ExternalMatchResponse`
+        fn assemble_quote(
+            &self,
+            quote: SignedExternalQuote,
+        ) -> Result<Option<ExternalMatchResponse>, ExternalMatchClientError>;
+
+        /// Assemble a quote into a match bundle with options (v1 API)
+        ///
+        /// Returns a non-malleable `ExternalMatchResponse`
+        fn assemble_quote_with_options(
+            &self,
+            quote: SignedExternalQuote,
+            options: AssembleQuoteOptions,
+        ) -> Result<Option<ExternalMatchResponse>, ExternalMatchClientError>;
+
+        /// Request an external match (v1 API)
+        ///
+        /// Returns a non-malleable `ExternalMatchResponse`
+        fn request_external_match(
+            &self,
+            order: ExternalOrder,
+        ) -> Result<Option<ExternalMatchResponse>, ExternalMatchClientError>;
+
+        /// Request an external match with options (v1 API)
+        ///
+        /// Returns a non-malleable `ExternalMatchResponse`
+        fn request_external_match_with_options(
+            &self,
+            order: ExternalOrder,
+            options: ExternalMatchOptions,
+        ) -> Result<Option<ExternalMatchResponse>, ExternalMatchClientError>;
+    }
+}
@@ -0,0 +1,57 @@
+//! Blocking (synchronous) facades over the async SDK clients
+//!
+//! Each blocking client wraps its async counterpart and drives it on an
+//! internal multi-threaded tokio runtime. The runtime is shared between clones
+//! of a client, and keeps servicing background work -- e.g. websocket
+//! subscriptions -- between calls.
+//!
+//! The blocking clients must not be used from within an async runtime; doing
+//! so will panic.
+
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+#[cfg(feature = "external-match-client")]
+mod external_match;
+#[cfg(feature = "external-match-client")]
+pub use external_match::ExternalMatchClient;
+
+#[cfg(feature = "darkpool-client")]
+mod renegade;
+#[cfg(feature = "darkpool-client")]
+pub use renegade::{BatchResult, RenegadeClient, Subscription, TaskWaiter};
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Build the runtime used to drive a blocking client
+fn build_runtime() -> Result<Arc<Runtime>, std::io::Error> {
+    Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("renegade-sdk-blocking")
+        .build()
+        .map(Arc::new)
+}
+
+/// Generate blocking wrappers around the inner client's async methods
+///
+/// Each wrapper has the same name, arguments, and return type as the async
+/// method it wraps, and blocks on the method using the client's runtime
+macro_rules! blocking_methods {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                #[allow(deprecated)]
+                let fut = self.inner.$name($($arg),*);
+                self.runtime.block_on(fut)
+            }
+        )*
+    };
+}
+pub(crate) use blocking_methods;
@@ -0,0 +1,569 @@
+//! A blocking facade over the Renegade wallet client
+
+use std::{pin::Pin, sync::Arc, time::Duration};
+
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use futures_util::{Stream, StreamExt};
+use renegade_circuit_types::Amount;
+use renegade_darkpool_types::csprng::PoseidonCSPRNG;
+use renegade_external_api::types::{
+    ApiAccount, ApiAdminOrder, ApiBalance, ApiOrder, ApiTask,
+    websocket::{
+        AdminBalanceUpdateMessage, AdminOrderUpdateMessage, BalanceUpdateMessage, FillMessage,
+        OrderUpdateMessage, TaskUpdateMessage,
+    },
+};
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+use crate::{
+    HmacKey, NetworkConfig, RenegadeClientError,
+    actions::{
+        admin_place_order_in_pool::{AdminOrderBuilder, BuiltAdminOrder},
+        batch::BatchResult as AsyncBatchResult,
+        place_order::{BuiltOrder, OrderBuilder},
+        update_order::OrderUpdateConfig,
+        withdraw::WithdrawalPreview,
+    },
+    client::AccountSecrets,
+    config::RenegadeClientConfig,
+    expiry::{Expiry, ExpiryStore, OrderExpiry},
+    websocket::{TaskState, TaskWaiter as AsyncTaskWaiter},
+};
+
+use super::{blocking_methods, build_runtime};
+
+/// The async client wrapped by the blocking client
+type AsyncRenegadeClient = crate::client::RenegadeClient;
+
+/// A boxed stream of websocket messages
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = T> + Send>>;
+
+// ----------
+// | Client |
+// ----------
+
+/// A blocking Renegade wallet client
+///
+/// Mirrors the async [`RenegadeClient`](crate::client::RenegadeClient)
+#[derive(Clone)]
+pub struct RenegadeClient {
+    /// The async client
+    inner: AsyncRenegadeClient,
+    /// The runtime used to drive the async client
+    runtime: Arc<Runtime>,
+}
+
+impl RenegadeClient {
+    // ----------------
+    // | Constructors |
+    // ----------------
+
+    /// Wrap an async client in a blocking client
+    pub fn from_async(inner: AsyncRenegadeClient) -> Result<Self, RenegadeClientError> {
+        let runtime = build_runtime().map_err(RenegadeClientError::setup)?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Derive the wallet secrets from an ethereum private key
+    pub fn new(config: RenegadeClientConfig) -> Result<Self, RenegadeClientError> {
+        let runtime = build_runtime().map_err(RenegadeClientError::setup)?;
+        let inner = runtime.block_on(AsyncRenegadeClient::new(config))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Create a new wallet on the given network
+    pub fn from_network(
+        network: impl Into<NetworkConfig>,
+        key: &PrivateKeySigner,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::from_network(network, key))
+    }
+
+    /// Create a new admin wallet on the given network
+    pub fn from_network_admin(
+        network: impl Into<NetworkConfig>,
+        key: &PrivateKeySigner,
+        admin_hmac_key: HmacKey,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::from_network_admin(network, key, admin_hmac_key))
+    }
+
+    /// Create a client from previously derived account secrets
+    pub fn from_secrets(
+        config: RenegadeClientConfig,
+        secrets: AccountSecrets,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::from_async(AsyncRenegadeClient::from_secrets(config, secrets))
+    }
+
+    /// Create a client for the account at the given index under the same
+    /// private key
+    pub fn with_account_index(&self, account_index: u32) -> Result<Self, RenegadeClientError> {
+        let inner = self.runtime.block_on(self.inner.with_account_index(account_index))?;
+        Ok(Self { inner, runtime: self.runtime.clone() })
+    }
+
+    /// Get a reference to the underlying async client
+    pub fn as_async(&self) -> &AsyncRenegadeClient {
+        &self.inner
+    }
+
+    /// Get the ID of the account
+    pub fn get_account_id(&self) -> Uuid {
+        self.inner.get_account_id()
+    }
+
+    /// Get the address of the account associated with the private key the
+    /// client is configured with
+    pub fn get_account_address(&self) -> Address {
+        self.inner.get_account_address()
+    }
+
+    /// Get the chain ID the client is configured for
+    pub fn get_chain_id(&self) -> u64 {
+        self.inner.get_chain_id()
+    }
+
+    /// Create a new order builder for the account
+    pub fn new_order_builder(&self) -> OrderBuilder {
+        self.inner.new_order_builder()
+    }
+
+    /// Create a new admin order builder for the account
+    pub fn new_admin_order_builder(&self) -> AdminOrderBuilder {
+        self.inner.new_admin_order_builder()
+    }
+
+    // -----------
+    // | Actions |
+    // -----------
+
+    blocking_methods! {
+        /// Create an account with the relayer.
+        ///
+        /// This method will register the account credentials with the relayer,
+        /// but will not yet result in any state being committed onchain in the
+        /// darkpool.
+        fn create_account(&self) -> Result<(), RenegadeClientError>;
+
+        /// Look up an account by its ID
+        ///
+        /// Returns the account's orders and balances
+        fn get_account(&self) -> Result<ApiAccount, RenegadeClientError>;
+
+        /// Get an account's seed CSPRNG states from the relayer.
+        ///
+        /// Returns a tuple of (recovery stream seeds CSPRNG, share stream seeds
+        /// CSPRNG)
+        fn get_account_seeds(
+            &self,
+        ) -> Result<(PoseidonCSPRNG, PoseidonCSPRNG), RenegadeClientError>;
+
+        /// Resync the client's seed CSPRNGs with the relayer
+        fn resync_account_seeds(&self) -> Result<(), RenegadeClientError>;
+
+        /// Sync an account with onchain state. Blocks until the sync task
+        /// completes.
+        fn sync_account(&self) -> Result<(), RenegadeClientError>;
+
+        /// Sync an account with onchain state, additionally forcing a balance
+        /// refresh for the given tokens. Blocks until the sync task completes.
+        fn sync_account_with_tokens(
+            &self,
+            additional_tokens: Vec<Address>,
+        ) -> Result<(), RenegadeClientError>;
+
+        /// Fetches all balances in the account.
+        fn get_balances(&self) -> Result<Vec<ApiBalance>, RenegadeClientError>;
+
+        /// Get the account's balance for a given mint
+        fn get_balance_by_mint(&self, mint: Address) -> Result<ApiBalance, RenegadeClientError>;
+
+        /// Deposit funds into an account balance. Blocks until the deposit task
+        /// completes.
+        fn deposit(&self, mint: Address, amount: Amount) -> Result<(), RenegadeClientError>;
+
+        /// Withdraw funds from an account balance. Blocks until the withdrawal
+        /// task completes.
+        fn withdraw(&self, mint: Address, amount: Amount) -> Result<(), RenegadeClientError>;
+
+        /// Preview the amount that can be withdrawn from a balance, after the
+        /// relayer & protocol fees it owes are paid
+        fn preview_withdrawal(&self, mint: Address) -> Result<WithdrawalPreview, RenegadeClientError>;
+
+        /// Withdraw the full amount that remains in a balance after its fees
+        /// are paid. Blocks until the withdrawal task completes.
+        fn withdraw_all(&self, mint: Address) -> Result<Amount, RenegadeClientError>;
+
+        /// Look up an order by its ID
+        fn get_order(&self, order_id: Uuid) -> Result<ApiOrder, RenegadeClientError>;
+
+        /// Fetches all orders in the account, optionally including historic
+        /// (inactive) orders.
+        fn get_orders(
+            &self,
+            include_historic_orders: bool,
+        ) -> Result<Vec<ApiOrder>, RenegadeClientError>;
+
+        /// Places an order via the relayer. Blocks until the order creation
+        /// task completes.
+        fn place_order(&self, built_order: BuiltOrder) -> Result<(), RenegadeClientError>;
+
+        /// Updates an order.
+        fn update_order(
+            &self,
+            order_update_config: OrderUpdateConfig,
+        ) -> Result<ApiOrder, RenegadeClientError>;
+
+        /// Cancels the order with the given ID. Blocks until the order
+        /// cancellation task completes.
+        fn cancel_order(&self, order_id: Uuid) -> Result<(), RenegadeClientError>;
+
+        /// Look up a task by its ID
+        fn get_task(&self, task_id: Uuid) -> Result<ApiTask, RenegadeClientError>;
+
+        /// Fetches all tasks in the account, optionally including historic
+        /// tasks.
+        fn get_tasks(
+            &self,
+            include_historic_tasks: bool,
+        ) -> Result<Vec<ApiTask>, RenegadeClientError>;
+    }
+
+    // -----------------
+    // | Admin Actions |
+    // -----------------
+
+    blocking_methods! {
+        /// Assigns an order to a specific matching pool via the admin API.
+        fn admin_assign_order_to_pool(
+            &self,
+            order_id: Uuid,
+            matching_pool: String,
+        ) -> Result<(), RenegadeClientError>;
+
+        /// Creates a new matching pool via the admin API.
+        fn admin_create_matching_pool(
+            &self,
+            matching_pool: String,
+        ) -> Result<(), RenegadeClientError>;
+
+        /// Fetches all orders for the given account (admin).
+        fn admin_get_account_orders(
+            &self,
+            account_id: Uuid,
+        ) -> Result<Vec<ApiAdminOrder>, RenegadeClientError>;
+
+        /// Fetches all open orders managed by the relayer.
+        fn admin_get_open_orders(&self) -> Result<Vec<ApiAdminOrder>, RenegadeClientError>;
+
+        /// Fetches all open orders managed by the relayer in the given matching
+        /// pool.
+        fn admin_get_open_orders_in_matching_pool(
+            &self,
+            matching_pool: String,
+        ) -> Result<Vec<ApiAdminOrder>, RenegadeClientError>;
+
+        /// Look up an order by its ID
+        fn admin_get_order(&self, order_id: Uuid) -> Result<ApiAdminOrder, RenegadeClientError>;
+
+        /// Check if the given account's task queue is paused
+        fn admin_is_task_queue_paused(
+            &self,
+            account_id: Uuid,
+        ) -> Result<bool, RenegadeClientError>;
+
+        /// Places an order in a specific matching pool via the admin API.
+        /// Blocks until the order creation task completes.
+        fn admin_place_order_in_pool(
+            &self,
+            built_order: BuiltAdminOrder,
+        ) -> Result<(), RenegadeClientError>;
+    }
+
+    // ------------------
+    // | Enqueued Tasks |
+    // ------------------
+
+    /// Enqueues a sync task in the relayer. Returns a `TaskWaiter` that can be
+    /// used to block until task completion.
+    pub fn enqueue_sync_account(&self) -> Result<TaskWaiter, RenegadeClientError> {
+        let waiter = self.runtime.block_on(self.inner.enqueue_sync_account())?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Enqueues a sync task in the relayer, additionally forcing a balance
+    /// refresh for the given tokens. Returns a `TaskWaiter` that can be used
+    /// to block until task completion.
+    pub fn enqueue_sync_account_with_tokens(
+        &self,
+        additional_tokens: Vec<Address>,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let fut = self.inner.enqueue_sync_account_with_tokens(additional_tokens);
+        let waiter = self.runtime.block_on(fut)?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Enqueues a deposit task in the relayer. Returns a `TaskWaiter` that can
+    /// be used to block until task completion.
+    pub fn enqueue_deposit(
+        &self,
+        mint: Address,
+        amount: Amount,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let waiter = self.runtime.block_on(self.inner.enqueue_deposit(mint, amount))?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Enqueues a withdrawal task in the relayer. Returns a `TaskWaiter` that
+    /// can be used to block until task completion.
+    pub fn enqueue_withdrawal(
+        &self,
+        mint: Address,
+        amount: Amount,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let waiter = self.runtime.block_on(self.inner.enqueue_withdrawal(mint, amount))?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Enqueues an order placement task in the relayer. Returns a
+    /// `TaskWaiter` that can be used to block until task completion.
+    pub fn enqueue_order_placement(
+        &self,
+        built_order: BuiltOrder,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let waiter = self.runtime.block_on(self.inner.enqueue_order_placement(built_order))?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Enqueues an order cancellation task in the relayer. Returns a
+    /// `TaskWaiter` that can be used to block until task completion.
+    pub fn enqueue_order_cancellation(
+        &self,
+        order_id: Uuid,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let waiter = self.runtime.block_on(self.inner.enqueue_order_cancellation(order_id))?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Enqueues an order placement task for each of the given orders. Returns,
+    /// for each order ID, a `TaskWaiter` or the error enqueuing the task.
+    pub fn place_orders(&self, built_orders: Vec<BuiltOrder>) -> BatchResult {
+        let results = self.runtime.block_on(self.inner.place_orders(built_orders));
+        self.batch_result(results)
+    }
+
+    /// Enqueues an order cancellation task for each of the given orders.
+    /// Returns, for each order ID, a `TaskWaiter` or the error enqueuing the
+    /// task.
+    pub fn cancel_orders(&self, order_ids: Vec<Uuid>) -> BatchResult {
+        let results = self.runtime.block_on(self.inner.cancel_orders(order_ids));
+        self.batch_result(results)
+    }
+
+    /// Enqueues an order cancellation task for each open order matching the
+    /// filter
+    pub fn cancel_all_orders(
+        &self,
+        filter: impl Fn(&ApiOrder) -> bool,
+    ) -> Result<BatchResult, RenegadeClientError> {
+        let results = self.runtime.block_on(self.inner.cancel_all_orders(filter))?;
+        Ok(self.batch_result(results))
+    }
+
+    /// Enqueues an order placement task in a specific matching pool via the
+    /// admin API. Returns a `TaskWaiter` that can be used to block until task
+    /// completion.
+    pub fn enqueue_admin_order_placement_in_pool(
+        &self,
+        built_order: BuiltAdminOrder,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let fut = self.inner.enqueue_admin_order_placement_in_pool(built_order);
+        let waiter = self.runtime.block_on(fut)?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    /// Create a `TaskWaiter` which can be used to watch a task until it
+    /// completes or times out
+    pub fn watch_task(
+        &self,
+        task_id: Uuid,
+        timeout: Duration,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let waiter = self.runtime.block_on(self.inner.watch_task(task_id, timeout))?;
+        Ok(self.task_waiter(waiter))
+    }
+
+    // -----------------
+    // | Subscriptions |
+    // -----------------
+
+    /// Subscribe to the account's task updates stream
+    pub fn subscribe_task_updates(
+        &self,
+    ) -> Result<Subscription<TaskUpdateMessage>, RenegadeClientError> {
+        let stream = self.runtime.block_on(self.inner.subscribe_task_updates())?;
+        Ok(self.subscription(stream))
+    }
+
+    /// Subscribe to the account's balance updates stream
+    pub fn subscribe_balance_updates(
+        &self,
+    ) -> Result<Subscription<BalanceUpdateMessage>, RenegadeClientError> {
+        let stream = self.runtime.block_on(self.inner.subscribe_balance_updates())?;
+        Ok(self.subscription(stream))
+    }
+
+    /// Subscribe to the account's order updates stream
+    pub fn subscribe_order_updates(
+        &self,
+    ) -> Result<Subscription<OrderUpdateMessage>, RenegadeClientError> {
+        let stream = self.runtime.block_on(self.inner.subscribe_order_updates())?;
+        Ok(self.subscription(stream))
+    }
+
+    /// Subscribe to the account's fills stream
+    pub fn subscribe_fills(&self) -> Result<Subscription<FillMessage>, RenegadeClientError> {
+        let stream = self.runtime.block_on(self.inner.subscribe_fills())?;
+        Ok(self.subscription(stream))
+    }
+
+    /// Subscribe to the admin balances updates stream
+    pub fn subscribe_admin_balance_updates(
+        &self,
+    ) -> Result<Subscription<AdminBalanceUpdateMessage>, RenegadeClientError> {
+        let stream = self.runtime.block_on(self.inner.subscribe_admin_balance_updates())?;
+        Ok(self.subscription(stream))
+    }
+
+    /// Subscribe to the admin order updates stream
+    pub fn subscribe_admin_order_updates(
+        &self,
+    ) -> Result<Subscription<AdminOrderUpdateMessage>, RenegadeClientError> {
+        let stream = self.runtime.block_on(self.inner.subscribe_admin_order_updates())?;
+        Ok(self.subscription(stream))
+    }
+
+    // ----------------
+    // | Order Expiry |
+    // ----------------
+
+    /// Start the scheduler that cancels orders at their expiry
+    ///
+    /// The scheduler runs on the client's runtime. See
+    /// [`RenegadeClient::enable_order_expiry`](crate::client::RenegadeClient::enable_order_expiry)
+    pub fn enable_order_expiry(
+        &self,
+        store: Option<Arc<dyn ExpiryStore>>,
+    ) -> Result<(), RenegadeClientError> {
+        let _guard = self.runtime.enter();
+        self.inner.enable_order_expiry(store)
+    }
+
+    /// Schedule an open order to be cancelled at the given expiry
+    ///
+    /// Requires the expiry scheduler to be enabled
+    pub fn schedule_order_expiry(
+        &self,
+        order_id: Uuid,
+        expiry: impl Into<Expiry>,
+    ) -> Result<(), RenegadeClientError> {
+        self.inner.schedule_order_expiry(order_id, expiry)
+    }
+
+    /// Remove an order's scheduled expiry, leaving the order open
+    pub fn cancel_order_expiry(&self, order_id: Uuid) -> Result<(), RenegadeClientError> {
+        self.inner.cancel_order_expiry(order_id)
+    }
+
+    /// Get the scheduled order expiries
+    pub fn order_expiries(&self) -> Vec<OrderExpiry> {
+        self.inner.order_expiries()
+    }
+
+    // -----------
+    // | Helpers |
+    // -----------
+
+    /// Wrap an async task waiter in a blocking task waiter
+    fn task_waiter(&self, waiter: AsyncTaskWaiter) -> TaskWaiter {
+        TaskWaiter { waiter, runtime: self.runtime.clone() }
+    }
+
+    /// Wrap the task waiters of a batch action in blocking task waiters
+    fn batch_result(&self, results: AsyncBatchResult) -> BatchResult {
+        results
+            .into_iter()
+            .map(|(order_id, waiter)| (order_id, waiter.map(|w| self.task_waiter(w))))
+            .collect()
+    }
+
+    /// Wrap a websocket stream in a blocking subscription
+    fn subscription<T, S>(&self, stream: S) -> Subscription<T>
+    where
+        S: Stream<Item = T> + Send + 'static,
+    {
+        Subscription { stream: Box::pin(stream), runtime: self.runtime.clone() }
+    }
+}
+
+// ---------------
+// | Task Waiter |
+// ---------------
+
+/// The outcome of a batch action: for each order ID, a blocking `TaskWaiter`
+/// for the order's task, or the error that prevented it from being enqueued
+pub type BatchResult = Vec<(Uuid, Result<TaskWaiter, RenegadeClientError>)>;
+
+/// A blocking handle to an enqueued task
+pub struct TaskWaiter {
+    /// The async task waiter
+    waiter: AsyncTaskWaiter,
+    /// The runtime used to drive the task waiter
+    runtime: Arc<Runtime>,
+}
+
+impl TaskWaiter {
+    /// The ID of the awaited task
+    pub fn task_id(&self) -> Uuid {
+        self.waiter.task_id()
+    }
+
+    /// Iterate over the states the task passes through, ending with the state
+    /// in which it completes or fails
+    ///
+    /// Each call to `next` blocks until the task's state changes
+    pub fn progress(&self) -> Subscription<TaskState> {
+        Subscription { stream: Box::pin(self.waiter.progress()), runtime: self.runtime.clone() }
+    }
+
+    /// Block until the task completes, fails, or times out, returning the
+    /// completed task
+    pub fn wait(self) -> Result<ApiTask, RenegadeClientError> {
+        self.runtime.block_on(self.waiter)
+    }
+}
+
+// ----------------
+// | Subscription |
+// ----------------
+
+/// A blocking iterator over the messages of a websocket subscription
+///
+/// Each call to `next` blocks until a message arrives. The iterator ends when
+/// the underlying subscription closes.
+pub struct Subscription<T> {
+    /// The underlying message stream
+    stream: BoxedStream<T>,
+    /// The runtime used to drive the stream
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Iterator for Subscription<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
@@ -1,14 +1,13 @@
 //! Types for the external match client
-pub mod exchange_metadata;
 mod fixed_point;
 mod malleable_match;
 pub mod markets;
 mod order_types;
 mod request_response;
 mod serde_helpers;
-pub mod token;
 pub mod v1_types;
 
+pub use crate::types::{exchange_metadata, token};
 pub use fixed_point::*;
 pub use order_types::*;
 pub use request_response::*;
@@ -27,7 +26,7 @@ pub const GET_MARKETS_DEPTH_ROUTE: &str = "/v2/markets/depth";
 pub const GET_MARKET_DEPTH_BY_MINT_ROUTE: &str = "/v2/markets/:mint/depth";
 
 /// Returns metadata about the Renegade exchange
-pub const GET_EXCHANGE_METADATA_ROUTE: &str = "/v2/metadata/exchange";
+pub use exchange_metadata::GET_EXCHANGE_METADATA_ROUTE;
 
 /// The route for requesting a quote on an external match
 pub const GET_QUOTE_ROUTE: &str = "/v2/external-matches/get-quote";
@@ -1,5 +1,6 @@
 //! Order types for the external match client
 
+use alloy::primitives::keccak256;
 use alloy_rpc_types_eth::TransactionRequest;
 use num_bigint::BigUint;
 use num_traits::Zero;
@@ -14,6 +15,9 @@ use super::{FixedPoint, serde_helpers::*};
 /// A type alias for an amount used in the Renegade system
 pub type Amount = u128;
 
+/// The number of signature digest bytes used in a quote ID
+const QUOTE_ID_BYTES: usize = 8;
+
 /// The side of the market this order is on
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum OrderSide {
@@ -216,6 +220,15 @@ impl SignedExternalQuoteV2 {
             gas_sponsorship_info,
         }
     }
+
+    /// Get a short identifier for the quote, derived from its signature
+    ///
+    /// Useful for correlating a quote with its assembly in logs and traces
+    pub fn quote_id(&self) -> String {
+        let digest = keccak256(self.signature.as_bytes());
+        hex::encode(&digest[..QUOTE_ID_BYTES])
+    }
+
     /// Get the match result from the quote
     pub fn match_result(&self) -> ApiExternalMatchResultV2 {
         self.quote.match_result.clone()
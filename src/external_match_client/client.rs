@@ -1,5 +1,7 @@
 //! The client for requesting external matches
 
+use std::{fmt, time::Instant};
+
 use crate::auth::HmacKey;
 use reqwest::{
     StatusCode,
@@ -7,9 +9,7 @@ use reqwest::{
 };
 
 use crate::{
-    ARBITRUM_ONE_RELAYER_BASE_URL, ARBITRUM_SEPOLIA_RELAYER_BASE_URL, AssembleQuoteOptionsV2,
-    BASE_MAINNET_RELAYER_BASE_URL, BASE_SEPOLIA_RELAYER_BASE_URL,
-    ETHEREUM_SEPOLIA_RELAYER_BASE_URL, ExternalMatchOptions, RequestQuoteOptions,
+    AssembleQuoteOptionsV2, ExternalMatchOptions, RequestQuoteOptions,
     api_types::{
         ASSEMBLE_MATCH_BUNDLE_ROUTE, AssemblyType, ExternalMatchResponseV2,
         GET_MARKET_DEPTH_BY_MINT_ROUTE, GET_MARKETS_DEPTH_ROUTE, GET_MARKETS_ROUTE,
@@ -20,6 +20,10 @@ use crate::{
 
 #[allow(deprecated)]
 use crate::http::RelayerHttpClient;
+use crate::network::{Network, NetworkConfig};
+#[cfg(feature = "settings")]
+use crate::settings::{ClientSettings, ConfigError};
+use crate::telemetry;
 
 use super::{
     api_types::{
@@ -27,6 +31,7 @@ use super::{
         ExternalQuoteResponse, GET_EXCHANGE_METADATA_ROUTE, SignedExternalQuoteV2,
     },
     error::ExternalMatchClientError,
+    hedging::{HedgeConfig, QuoteLatencyStats, QuoteTracker},
 };
 
 // -------------
@@ -36,23 +41,12 @@ use super::{
 /// The Renegade API key header
 pub const RENEGADE_API_KEY_HEADER: &str = "X-Renegade-Api-Key";
 
-/// The Arbitrum Sepolia auth server base URL
-const ARBITRUM_SEPOLIA_AUTH_BASE_URL: &str = "https://arbitrum-sepolia.v2.auth-server.renegade.fi";
-/// The Arbitrum One auth server base URL
-const ARBITRUM_ONE_AUTH_BASE_URL: &str = "https://arbitrum-one.v2.auth-server.renegade.fi";
-/// The Base Sepolia auth server base URL
-const BASE_SEPOLIA_AUTH_BASE_URL: &str = "https://base-sepolia.v2.auth-server.renegade.fi";
-/// The Base mainnet auth server base URL
-const BASE_MAINNET_AUTH_BASE_URL: &str = "https://base-mainnet.v2.auth-server.renegade.fi";
-/// The Ethereum Sepolia auth server base URL
-const ETHEREUM_SEPOLIA_AUTH_BASE_URL: &str = "https://ethereum-sepolia.v2.auth-server.renegade.fi";
-
 // ----------
 // | Client |
 // ----------
 
 /// A client for requesting external matches from the relayer
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct ExternalMatchClient {
     /// The api key for the external match client
     pub(crate) api_key: String,
@@ -62,6 +56,18 @@ pub struct ExternalMatchClient {
     ///
     /// Separate from the auth client as they request different base URLs
     pub(crate) relayer_http_client: RelayerHttpClient,
+    /// Tracks quote latencies and the quote rate limit for hedged requests
+    pub(crate) quote_tracker: QuoteTracker,
+}
+
+impl fmt::Debug for ExternalMatchClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalMatchClient")
+            .field("api_key", &"[REDACTED]")
+            .field("auth_http_client", &self.auth_http_client)
+            .field("relayer_http_client", &self.relayer_http_client)
+            .finish()
+    }
 }
 
 impl ExternalMatchClient {
@@ -77,8 +83,9 @@ impl ExternalMatchClient {
 
         Ok(Self {
             api_key: api_key.to_string(),
-            auth_http_client: RelayerHttpClient::new(auth_base_url.to_string(), api_secret),
+            auth_http_client: RelayerHttpClient::new(auth_base_url.to_string(), api_secret.clone()),
             relayer_http_client: RelayerHttpClient::new(relayer_base_url.to_string(), api_secret),
+            quote_tracker: QuoteTracker::default(),
         })
     }
 
@@ -94,28 +101,85 @@ impl ExternalMatchClient {
             .map_err(|_| ExternalMatchClientError::InvalidApiSecret)?;
         let auth_http_client = RelayerHttpClient::new_with_client(
             auth_base_url.to_string(),
-            api_secret,
+            api_secret.clone(),
             client.clone(),
         );
         let relayer_http_client =
             RelayerHttpClient::new_with_client(relayer_base_url.to_string(), api_secret, client);
 
-        Ok(Self { api_key: api_key.to_string(), auth_http_client, relayer_http_client })
+        Ok(Self {
+            api_key: api_key.to_string(),
+            auth_http_client,
+            relayer_http_client,
+            quote_tracker: QuoteTracker::default(),
+        })
     }
 
-    /// Create a new client for the Ethereum Sepolia network
-    pub fn new_ethereum_sepolia_client(
+    /// Create a new client for the given network
+    pub fn from_network(
         api_key: &str,
         api_secret: &str,
+        network: impl Into<NetworkConfig>,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new(
+        let network = network.into();
+        Self::new(api_key, api_secret, &network.auth_base_url, &network.relayer_base_url)
+    }
+
+    /// Create a new client for the given network with custom HTTP client
+    pub fn from_network_with_client(
+        api_key: &str,
+        api_secret: &str,
+        network: impl Into<NetworkConfig>,
+        client: reqwest::Client,
+    ) -> Result<Self, ExternalMatchClientError> {
+        let network = network.into();
+        Self::new_with_client(
             api_key,
             api_secret,
-            ETHEREUM_SEPOLIA_AUTH_BASE_URL,
-            ETHEREUM_SEPOLIA_RELAYER_BASE_URL,
+            &network.auth_base_url,
+            &network.relayer_base_url,
+            client,
         )
     }
 
+    /// Create a new client from loaded settings
+    ///
+    /// Requires a network and API credentials
+    #[cfg(feature = "settings")]
+    pub fn from_settings(settings: &ClientSettings) -> Result<Self, ConfigError> {
+        let network = settings.network_config()?;
+        let (api_key, api_secret) = settings.api_credentials()?;
+
+        Self::from_network(api_key, api_secret, network).map_err(|e| ConfigError::Invalid {
+            name: "external match credentials".to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Create a new client from environment variables
+    ///
+    /// See [`crate::settings`] for the variables read
+    #[cfg(feature = "settings")]
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_settings(&ClientSettings::from_env()?)
+    }
+
+    /// Create a new client from a TOML or JSON file
+    ///
+    /// See [`crate::settings`] for the keys read
+    #[cfg(feature = "settings")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        Self::from_settings(&ClientSettings::from_file(path)?)
+    }
+
+    /// Create a new client for the Ethereum Sepolia network
+    pub fn new_ethereum_sepolia_client(
+        api_key: &str,
+        api_secret: &str,
+    ) -> Result<Self, ExternalMatchClientError> {
+        Self::from_network(api_key, api_secret, Network::EthereumSepolia)
+    }
+
     /// Create a new client for the Ethereum Sepolia network with custom HTTP
     /// client
     pub fn new_ethereum_sepolia_with_client(
@@ -123,13 +187,7 @@ impl ExternalMatchClient {
         api_secret: &str,
         client: reqwest::Client,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new_with_client(
-            api_key,
-            api_secret,
-            ETHEREUM_SEPOLIA_AUTH_BASE_URL,
-            ETHEREUM_SEPOLIA_RELAYER_BASE_URL,
-            client,
-        )
+        Self::from_network_with_client(api_key, api_secret, Network::EthereumSepolia, client)
     }
 
     /// Create a new client for the Arbitrum Sepolia network
@@ -137,12 +195,7 @@ impl ExternalMatchClient {
         api_key: &str,
         api_secret: &str,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new(
-            api_key,
-            api_secret,
-            ARBITRUM_SEPOLIA_AUTH_BASE_URL,
-            ARBITRUM_SEPOLIA_RELAYER_BASE_URL,
-        )
+        Self::from_network(api_key, api_secret, Network::ArbitrumSepolia)
     }
 
     /// Create a new client for the Base Sepolia network
@@ -150,7 +203,7 @@ impl ExternalMatchClient {
         api_key: &str,
         api_secret: &str,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new(api_key, api_secret, BASE_SEPOLIA_AUTH_BASE_URL, BASE_SEPOLIA_RELAYER_BASE_URL)
+        Self::from_network(api_key, api_secret, Network::BaseSepolia)
     }
 
     /// Create a new client for the Arbitrum One network
@@ -158,7 +211,7 @@ impl ExternalMatchClient {
         api_key: &str,
         api_secret: &str,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new(api_key, api_secret, ARBITRUM_ONE_AUTH_BASE_URL, ARBITRUM_ONE_RELAYER_BASE_URL)
+        Self::from_network(api_key, api_secret, Network::ArbitrumOne)
     }
 
     /// Create a new client for the Arbitrum One network with custom HTTP client
@@ -167,13 +220,7 @@ impl ExternalMatchClient {
         api_secret: &str,
         client: reqwest::Client,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new_with_client(
-            api_key,
-            api_secret,
-            ARBITRUM_ONE_AUTH_BASE_URL,
-            ARBITRUM_ONE_RELAYER_BASE_URL,
-            client,
-        )
+        Self::from_network_with_client(api_key, api_secret, Network::ArbitrumOne, client)
     }
 
     /// Create a new client for the Base mainnet network
@@ -181,7 +228,7 @@ impl ExternalMatchClient {
         api_key: &str,
         api_secret: &str,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new(api_key, api_secret, BASE_MAINNET_AUTH_BASE_URL, BASE_MAINNET_RELAYER_BASE_URL)
+        Self::from_network(api_key, api_secret, Network::BaseMainnet)
     }
 
     /// Create a new client for the Base mainnet network with custom HTTP client
@@ -190,13 +237,7 @@ impl ExternalMatchClient {
         api_secret: &str,
         client: reqwest::Client,
     ) -> Result<Self, ExternalMatchClientError> {
-        Self::new_with_client(
-            api_key,
-            api_secret,
-            BASE_MAINNET_AUTH_BASE_URL,
-            BASE_MAINNET_RELAYER_BASE_URL,
-            client,
-        )
+        Self::from_network_with_client(api_key, api_secret, Network::BaseMainnet, client)
     }
 
     // ------------------
@@ -205,6 +246,7 @@ impl ExternalMatchClient {
 
     /// Get a list of tradable markets. Includes the tokens pair, current price,
     /// and fee rates for each market.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_markets(&self) -> Result<GetMarketsResponse, ExternalMatchClientError> {
         let path = GET_MARKETS_ROUTE;
         let headers = self.get_headers()?;
@@ -216,6 +258,7 @@ impl ExternalMatchClient {
     /// Get the market depth for the given token.
     ///
     /// The address is the address of the token
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(mint = %address)))]
     pub async fn get_market_depth(
         &self,
         address: &str,
@@ -228,6 +271,7 @@ impl ExternalMatchClient {
     }
 
     /// Get the market depths for all supported pairs
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_market_depths_all_pairs(
         &self,
     ) -> Result<GetMarketDepthsResponse, ExternalMatchClientError> {
@@ -243,6 +287,7 @@ impl ExternalMatchClient {
     // -------------------------
 
     /// Request a quote for an external match (v2 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_quote_v2(
         &self,
         order: ExternalOrderV2,
@@ -251,6 +296,7 @@ impl ExternalMatchClient {
     }
 
     /// Request a quote for an external match, with options (v2 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(hedged = options.hedge.is_some(), quote_id = tracing::field::Empty)))]
     pub async fn request_quote_with_options_v2(
         &self,
         order: ExternalOrderV2,
@@ -258,15 +304,33 @@ impl ExternalMatchClient {
     ) -> Result<Option<SignedExternalQuoteV2>, ExternalMatchClientError> {
         let request = ExternalQuoteRequest { external_order: order };
         let path = options.build_request_path();
-        let headers = self.get_headers()?;
 
-        let resp = self.auth_http_client.post_with_headers_raw(&path, request, headers).await?;
-        let quote_resp = Self::handle_optional_response::<ExternalQuoteResponse>(resp).await?;
-        Ok(quote_resp
-            .map(|r| SignedExternalQuoteV2::from_api_quote(r.signed_quote, r.gas_sponsorship_info)))
+        let quote_resp = match options.hedge {
+            Some(hedge) => self.send_hedged_quote_request(&path, &request, &hedge).await?,
+            None => {
+                self.quote_tracker.record_request();
+                self.send_quote_request(&path, &request).await?
+            },
+        };
+
+        let quote = quote_resp
+            .map(|r| SignedExternalQuoteV2::from_api_quote(r.signed_quote, r.gas_sponsorship_info));
+        if let Some(quote) = &quote {
+            telemetry::record_quote_id(&quote.quote_id());
+        }
+
+        Ok(quote)
+    }
+
+    /// Get a snapshot of the client's quote latency statistics
+    ///
+    /// Useful for tuning the percentile used by hedged quote requests
+    pub fn quote_latency_stats(&self) -> QuoteLatencyStats {
+        self.quote_tracker.stats()
     }
 
     /// Assemble a quote into a match bundle, ready for settlement (v2 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn assemble_quote_v2(
         &self,
         quote: SignedExternalQuoteV2,
@@ -276,6 +340,7 @@ impl ExternalMatchClient {
 
     /// Assemble a quote into a match bundle, ready for settlement, with options
     /// (v2 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(quote_id = %quote.quote_id())))]
     pub async fn assemble_quote_with_options_v2(
         &self,
         quote: SignedExternalQuoteV2,
@@ -301,6 +366,7 @@ impl ExternalMatchClient {
     }
 
     /// Request an external match (v2 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_external_match_v2(
         &self,
         order: ExternalOrderV2,
@@ -310,6 +376,7 @@ impl ExternalMatchClient {
 
     /// Request an external match and specify any options for the request (v2
     /// API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_external_match_with_options_v2(
         &self,
         order: ExternalOrderV2,
@@ -337,6 +404,7 @@ impl ExternalMatchClient {
     // -------------------
 
     /// Get metadata about the Renegade exchange
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_exchange_metadata(
         &self,
     ) -> Result<ExchangeMetadataResponse, ExternalMatchClientError> {
@@ -351,6 +419,99 @@ impl ExternalMatchClient {
     // | Helpers |
     // -----------
 
+    /// Send a single quote request, recording its latency
+    async fn send_quote_request(
+        &self,
+        path: &str,
+        request: &ExternalQuoteRequest,
+    ) -> Result<Option<ExternalQuoteResponse>, ExternalMatchClientError> {
+        let headers = self.get_headers()?;
+        let start = Instant::now();
+
+        let resp = self.auth_http_client.post_with_headers_raw(path, request, headers).await?;
+        let quote_resp = Self::handle_optional_response::<ExternalQuoteResponse>(resp).await?;
+
+        self.quote_tracker.record_latency(start.elapsed());
+        Ok(quote_resp)
+    }
+
+    /// Send a hedged quote request
+    ///
+    /// If the primary request has not returned after the hedge delay, a
+    /// duplicate request is sent -- rate limit permitting -- and the first
+    /// successful response is returned. If one request fails, the other is
+    /// awaited before surfacing an error.
+    ///
+    /// Both requests run on detached tasks, so the request whose response is
+    /// not used still runs to completion & records its latency. Recording only
+    /// the winner would skew the latency distribution toward fast responses,
+    /// & so send hedges too early.
+    async fn send_hedged_quote_request(
+        &self,
+        path: &str,
+        request: &ExternalQuoteRequest,
+        hedge: &HedgeConfig,
+    ) -> Result<Option<ExternalQuoteResponse>, ExternalMatchClientError> {
+        let delay = self.quote_tracker.hedge_delay(hedge);
+        self.quote_tracker.record_request();
+
+        let primary = self.spawn_quote_request(path, request);
+        tokio::pin!(primary);
+
+        tokio::select! {
+            res = &mut primary => return res,
+            _ = tokio::time::sleep(delay) => {},
+        }
+
+        if !self.quote_tracker.try_acquire_hedge(hedge) {
+            return primary.await;
+        }
+
+        let hedged = self.spawn_quote_request(path, request);
+        tokio::pin!(hedged);
+
+        tokio::select! {
+            res = &mut primary => match res {
+                Ok(resp) => Ok(resp),
+                Err(_) => hedged.await.inspect(|_| self.quote_tracker.record_hedge_won()),
+            },
+            res = &mut hedged => match res {
+                Ok(resp) => {
+                    self.quote_tracker.record_hedge_won();
+                    Ok(resp)
+                },
+                Err(_) => primary.await,
+            },
+        }
+    }
+
+    /// Send a quote request on a detached task, returning a future that
+    /// resolves to its response
+    ///
+    /// The request runs to completion & records its latency even if the
+    /// returned future is dropped
+    fn spawn_quote_request(
+        &self,
+        path: &str,
+        request: &ExternalQuoteRequest,
+    ) -> impl Future<Output = Result<Option<ExternalQuoteResponse>, ExternalMatchClientError>> + use<>
+    {
+        let client = self.clone();
+        let path = path.to_string();
+        let request = request.clone();
+        let fut = async move { client.send_quote_request(&path, &request).await };
+        // Keep the request within the caller's span
+        #[cfg(feature = "tracing")]
+        let fut = tracing::Instrument::in_current_span(fut);
+        let task = tokio::spawn(fut);
+
+        async move {
+            task.await.map_err(|e| {
+                ExternalMatchClientError::Http(None, format!("quote request task failed: {e}"))
+            })?
+        }
+    }
+
     /// Helper function to handle response that might be NO_CONTENT, OK with
     /// json, or an error
     pub(crate) async fn handle_optional_response<T>(
@@ -360,6 +521,7 @@ impl ExternalMatchClient {
         T: serde::de::DeserializeOwned,
     {
         if response.status() == StatusCode::NO_CONTENT {
+            telemetry::record_no_content(response.url().path());
             Ok(None)
         } else if response.status() == StatusCode::OK {
             let resp = response.json::<T>().await?;
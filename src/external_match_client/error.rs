@@ -28,6 +28,9 @@ pub enum ExternalMatchClientError {
     /// An error deserializing a response
     #[error("error deserializing a response: {0}")]
     Deserialize(String),
+    /// An error setting up the runtime that drives a blocking client
+    #[error("error building blocking client runtime: {0}")]
+    Runtime(String),
 }
 
 impl ExternalMatchClientError {
@@ -54,6 +57,13 @@ impl ExternalMatchClientError {
     pub(crate) fn deserialize<T: ToString>(msg: T) -> Self {
         Self::Deserialize(msg.to_string())
     }
+
+    /// Construct a new runtime error
+    #[allow(clippy::needless_pass_by_value)]
+    #[cfg_attr(not(feature = "blocking"), allow(dead_code))]
+    pub(crate) fn runtime<T: ToString>(msg: T) -> Self {
+        Self::Runtime(msg.to_string())
+    }
 }
 
 impl From<reqwest::Error> for ExternalMatchClientError {
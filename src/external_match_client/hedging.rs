@@ -0,0 +1,338 @@
+//! Hedged quote requests
+//!
+//! A hedged request sends a duplicate quote request if the first has not
+//! returned within a delay taken from the observed latency distribution, and
+//! returns whichever request succeeds first. Hedges are only sent while the
+//! client-side quote rate limiter has capacity, so hedging never pushes a
+//! client over the auth server's quote rate limit.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The number of latency samples retained for percentile estimation
+const LATENCY_WINDOW_SIZE: usize = 512;
+/// The minimum number of samples needed before percentiles are used to pick a
+/// hedge delay
+const MIN_SAMPLES_FOR_PERCENTILE: usize = 20;
+
+/// The default percentile of quote latency after which a hedge is sent
+const DEFAULT_HEDGE_PERCENTILE: f64 = 0.95;
+/// The default lower bound on the hedge delay
+const DEFAULT_MIN_HEDGE_DELAY: Duration = Duration::from_millis(50);
+/// The default upper bound on the hedge delay, also used before enough samples
+/// have been collected
+const DEFAULT_MAX_HEDGE_DELAY: Duration = Duration::from_millis(500);
+/// The default number of quote requests allowed per rate limit window
+///
+/// A conservative default, leaving headroom under the auth server's rate limit
+const DEFAULT_QUOTE_RATE_LIMIT: usize = 100;
+/// The window over which the quote rate limit is enforced
+const QUOTE_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+// ----------
+// | Config |
+// ----------
+
+/// The configuration for hedged quote requests
+#[derive(Clone, Debug)]
+pub struct HedgeConfig {
+    /// The percentile of observed quote latency after which a hedge is sent,
+    /// in the range `(0, 1]`
+    pub percentile: f64,
+    /// The minimum delay before a hedge is sent
+    pub min_delay: Duration,
+    /// The maximum delay before a hedge is sent
+    ///
+    /// This delay is also used until enough latency samples have been
+    /// collected to estimate the percentile
+    pub max_delay: Duration,
+    /// The maximum number of quote requests -- primary and hedged -- to send
+    /// per minute. Hedges are skipped once this limit is reached.
+    pub max_requests_per_minute: usize,
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self {
+            percentile: DEFAULT_HEDGE_PERCENTILE,
+            min_delay: DEFAULT_MIN_HEDGE_DELAY,
+            max_delay: DEFAULT_MAX_HEDGE_DELAY,
+            max_requests_per_minute: DEFAULT_QUOTE_RATE_LIMIT,
+        }
+    }
+}
+
+impl HedgeConfig {
+    /// Create a new hedge config with default values
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the latency percentile after which a hedge is sent
+    pub fn with_percentile(mut self, percentile: f64) -> Self {
+        self.percentile = percentile.clamp(f64::EPSILON, 1.0);
+        self
+    }
+
+    /// Set the minimum hedge delay
+    pub fn with_min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self
+    }
+
+    /// Set the maximum hedge delay
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the maximum number of quote requests per minute
+    pub fn with_max_requests_per_minute(mut self, max_requests: usize) -> Self {
+        self.max_requests_per_minute = max_requests;
+        self
+    }
+}
+
+// ---------
+// | Stats |
+// ---------
+
+/// A snapshot of the client's quote latency statistics
+#[derive(Clone, Debug, Default)]
+pub struct QuoteLatencyStats {
+    /// The number of latency samples in the window
+    pub samples: usize,
+    /// The median quote latency
+    pub p50: Option<Duration>,
+    /// The 90th percentile quote latency
+    pub p90: Option<Duration>,
+    /// The 95th percentile quote latency
+    pub p95: Option<Duration>,
+    /// The 99th percentile quote latency
+    pub p99: Option<Duration>,
+    /// The maximum quote latency in the window
+    pub max: Option<Duration>,
+    /// The total number of hedge requests sent
+    pub hedges_sent: u64,
+    /// The number of hedge requests whose response was used
+    pub hedges_won: u64,
+    /// The number of hedges skipped because the rate limiter was exhausted
+    pub hedges_rate_limited: u64,
+}
+
+// -----------------
+// | Quote Tracker |
+// -----------------
+
+/// Tracks quote latencies, hedge outcomes, and the quote rate limit
+///
+/// Shared between clones of an `ExternalMatchClient`
+#[derive(Clone, Debug, Default)]
+pub(crate) struct QuoteTracker {
+    /// The inner tracker state
+    inner: Arc<Mutex<QuoteTrackerInner>>,
+}
+
+/// The inner state of the quote tracker
+#[derive(Debug, Default)]
+struct QuoteTrackerInner {
+    /// The most recent quote latencies
+    latencies: VecDeque<Duration>,
+    /// The send times of quote requests in the current rate limit window
+    request_times: VecDeque<Instant>,
+    /// The total number of hedge requests sent
+    hedges_sent: u64,
+    /// The number of hedge requests whose response was used
+    hedges_won: u64,
+    /// The number of hedges skipped because the rate limiter was exhausted
+    hedges_rate_limited: u64,
+}
+
+impl QuoteTracker {
+    /// Record a quote latency sample
+    pub fn record_latency(&self, latency: Duration) {
+        let mut inner = self.inner.lock().expect("quote tracker lock poisoned");
+        if inner.latencies.len() == LATENCY_WINDOW_SIZE {
+            inner.latencies.pop_front();
+        }
+        inner.latencies.push_back(latency);
+    }
+
+    /// Record a primary quote request against the rate limit window
+    ///
+    /// Primary requests are never blocked by the limiter; they only consume
+    /// capacity that would otherwise be available to hedges
+    pub fn record_request(&self) {
+        self.record_request_at(Instant::now());
+    }
+
+    /// Record a primary quote request sent at the given time
+    fn record_request_at(&self, now: Instant) {
+        let mut inner = self.inner.lock().expect("quote tracker lock poisoned");
+        inner.prune_request_times(now);
+        inner.request_times.push_back(now);
+    }
+
+    /// Attempt to acquire rate limit capacity for a hedge request
+    ///
+    /// Returns `false` if sending the hedge would exceed the rate limit
+    pub fn try_acquire_hedge(&self, config: &HedgeConfig) -> bool {
+        self.try_acquire_hedge_at(config, Instant::now())
+    }
+
+    /// Attempt to acquire rate limit capacity for a hedge sent at the given
+    /// time
+    fn try_acquire_hedge_at(&self, config: &HedgeConfig, now: Instant) -> bool {
+        let mut inner = self.inner.lock().expect("quote tracker lock poisoned");
+        inner.prune_request_times(now);
+
+        if inner.request_times.len() >= config.max_requests_per_minute {
+            inner.hedges_rate_limited += 1;
+            return false;
+        }
+
+        inner.request_times.push_back(now);
+        inner.hedges_sent += 1;
+        true
+    }
+
+    /// Record that a hedge request's response was used
+    pub fn record_hedge_won(&self) {
+        let mut inner = self.inner.lock().expect("quote tracker lock poisoned");
+        inner.hedges_won += 1;
+    }
+
+    /// Compute the delay after which to send a hedge request
+    pub fn hedge_delay(&self, config: &HedgeConfig) -> Duration {
+        let inner = self.inner.lock().expect("quote tracker lock poisoned");
+        if inner.latencies.len() < MIN_SAMPLES_FOR_PERCENTILE {
+            return config.max_delay;
+        }
+
+        let sorted = inner.sorted_latencies();
+        let delay = percentile(&sorted, config.percentile).unwrap_or(config.max_delay);
+        delay.clamp(config.min_delay, config.max_delay.max(config.min_delay))
+    }
+
+    /// Get a snapshot of the latency statistics
+    pub fn stats(&self) -> QuoteLatencyStats {
+        let inner = self.inner.lock().expect("quote tracker lock poisoned");
+        let sorted = inner.sorted_latencies();
+
+        QuoteLatencyStats {
+            samples: sorted.len(),
+            p50: percentile(&sorted, 0.5),
+            p90: percentile(&sorted, 0.9),
+            p95: percentile(&sorted, 0.95),
+            p99: percentile(&sorted, 0.99),
+            max: sorted.last().copied(),
+            hedges_sent: inner.hedges_sent,
+            hedges_won: inner.hedges_won,
+            hedges_rate_limited: inner.hedges_rate_limited,
+        }
+    }
+}
+
+impl QuoteTrackerInner {
+    /// Remove request times that have fallen out of the rate limit window
+    fn prune_request_times(&mut self, now: Instant) {
+        while let Some(t) = self.request_times.front() {
+            if now.duration_since(*t) < QUOTE_RATE_LIMIT_WINDOW {
+                break;
+            }
+            self.request_times.pop_front();
+        }
+    }
+
+    /// Get the latency samples in ascending order
+    fn sorted_latencies(&self) -> Vec<Duration> {
+        let mut sorted: Vec<Duration> = self.latencies.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Get the nearest-rank percentile of a sorted list of samples
+fn percentile(sorted: &[Duration], pct: f64) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let rank = (pct * sorted.len() as f64).ceil() as usize;
+    let idx = rank.clamp(1, sorted.len()) - 1;
+    Some(sorted[idx])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a list of latency samples from millisecond values
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let sorted = millis(&[10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+
+        assert_eq!(percentile(&sorted, 0.5), Some(Duration::from_millis(50)));
+        assert_eq!(percentile(&sorted, 0.9), Some(Duration::from_millis(90)));
+        assert_eq!(percentile(&sorted, 0.95), Some(Duration::from_millis(100)));
+        assert_eq!(percentile(&sorted, 1.0), Some(Duration::from_millis(100)));
+        // Ranks below the first sample clamp to it
+        assert_eq!(percentile(&sorted, 0.01), Some(Duration::from_millis(10)));
+        assert_eq!(percentile(&[], 0.5), None);
+    }
+
+    #[test]
+    fn test_hedge_delay_uses_percentile_within_bounds() {
+        let tracker = QuoteTracker::default();
+        let config = HedgeConfig::new()
+            .with_percentile(0.5)
+            .with_min_delay(Duration::from_millis(15))
+            .with_max_delay(Duration::from_millis(80));
+
+        // Too few samples to estimate the percentile
+        tracker.record_latency(Duration::from_millis(10));
+        assert_eq!(tracker.hedge_delay(&config), config.max_delay);
+
+        for ms in 1..=MIN_SAMPLES_FOR_PERCENTILE as u64 {
+            tracker.record_latency(Duration::from_millis(ms * 5));
+        }
+        assert_eq!(tracker.hedge_delay(&config), Duration::from_millis(50));
+
+        let config = config.with_percentile(1.0);
+        assert_eq!(tracker.hedge_delay(&config), config.max_delay);
+    }
+
+    #[test]
+    fn test_rate_limit_window() {
+        let tracker = QuoteTracker::default();
+        let config = HedgeConfig::new().with_max_requests_per_minute(2);
+
+        let start = Instant::now();
+        tracker.record_request_at(start);
+        assert!(tracker.try_acquire_hedge_at(&config, start));
+        assert!(!tracker.try_acquire_hedge_at(&config, start));
+
+        // The recorded requests age out of the window
+        let later = start + QUOTE_RATE_LIMIT_WINDOW + Duration::from_secs(1);
+        assert!(tracker.try_acquire_hedge_at(&config, later));
+
+        let stats = tracker.stats();
+        assert_eq!(stats.hedges_sent, 2);
+        assert_eq!(stats.hedges_rate_limited, 1);
+    }
+}
@@ -7,11 +7,13 @@
 pub mod api_types;
 
 mod client;
+mod hedging;
 mod options;
 mod v1_client;
 mod v1_conversions;
 use api_types::{Amount, ExternalOrderV2, OrderSide, v1_types};
 pub use client::ExternalMatchClient;
+pub use hedging::{HedgeConfig, QuoteLatencyStats};
 #[allow(deprecated)]
 pub use options::{
     AssembleQuoteOptions, AssembleQuoteOptionsV2, ExternalMatchOptions, RequestQuoteOptions,
@@ -6,7 +6,7 @@ use crate::{
     api_types::{ASSEMBLE_MATCH_BUNDLE_ROUTE, ExternalOrderV2, GET_QUOTE_ROUTE, v1_types},
 };
 
-use super::{GAS_REFUND_ADDRESS_QUERY_PARAM, GAS_SPONSORSHIP_QUERY_PARAM};
+use super::{GAS_REFUND_ADDRESS_QUERY_PARAM, GAS_SPONSORSHIP_QUERY_PARAM, hedging::HedgeConfig};
 
 /// The options for requesting a quote
 #[derive(Clone, Default)]
@@ -17,6 +17,11 @@ pub struct RequestQuoteOptions {
     pub gas_refund_address: Option<String>,
     /// Whether to refund gas in terms of native ETH, as opposed to in-kind
     pub refund_native_eth: bool,
+    /// The hedging configuration for the request
+    ///
+    /// If set, a duplicate request is sent when no response has arrived after
+    /// a delay taken from the client's observed quote latency distribution
+    pub hedge: Option<HedgeConfig>,
 }
 
 impl RequestQuoteOptions {
@@ -43,6 +48,12 @@ impl RequestQuoteOptions {
         self
     }
 
+    /// Enable hedged requests with the given configuration
+    pub fn with_hedging(mut self, hedge: HedgeConfig) -> Self {
+        self.hedge = Some(hedge);
+        self
+    }
+
     /// Get the request path given the options
     pub(crate) fn build_request_path(&self) -> String {
         let mut query = form_urlencoded::Serializer::new(String::new());
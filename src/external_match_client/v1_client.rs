@@ -31,6 +31,7 @@ impl ExternalMatchClient {
         since = "2.0.0",
         note = "Use get_markets instead, which returns all supported tokens along with their current price"
     )]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_supported_tokens(
         &self,
     ) -> Result<GetSupportedTokensResponse, ExternalMatchClientError> {
@@ -43,6 +44,7 @@ impl ExternalMatchClient {
         since = "2.0.0",
         note = "Use get_markets instead, which returns all supported tokens along with their current price"
     )]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_token_prices(
         &self,
     ) -> Result<GetTokenPricesResponse, ExternalMatchClientError> {
@@ -54,6 +56,7 @@ impl ExternalMatchClient {
     ///
     /// The address is the address of the token
     #[deprecated(since = "2.0.0", note = "Use get_market_depth instead")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(mint = %address)))]
     pub async fn get_order_book_depth(
         &self,
         address: &str,
@@ -64,6 +67,7 @@ impl ExternalMatchClient {
 
     /// Get the order book depth for all supported tokens
     #[deprecated(since = "2.0.0", note = "Use get_market_depths_all_pairs instead")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_order_book_depth_all_pairs(
         &self,
     ) -> Result<GetDepthForAllPairsResponse, ExternalMatchClientError> {
@@ -76,6 +80,7 @@ impl ExternalMatchClient {
     // ---------------------
 
     /// Request a quote for an external match (v1 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_quote(
         &self,
         order: ExternalOrder,
@@ -84,6 +89,7 @@ impl ExternalMatchClient {
     }
 
     /// Request a quote for an external match, with options (v1 API)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_quote_with_options(
         &self,
         order: ExternalOrder,
@@ -97,6 +103,7 @@ impl ExternalMatchClient {
     /// Assemble a quote into a match bundle, ready for settlement (v1 API)
     ///
     /// Returns a non-malleable `ExternalMatchResponse`
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn assemble_quote(
         &self,
         quote: SignedExternalQuote,
@@ -107,6 +114,7 @@ impl ExternalMatchClient {
     /// Assemble a quote into a match bundle with options (v1 API)
     ///
     /// Returns a non-malleable `ExternalMatchResponse`
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn assemble_quote_with_options(
         &self,
         quote: SignedExternalQuote,
@@ -126,6 +134,7 @@ impl ExternalMatchClient {
     /// Request an external match (v1 API)
     ///
     /// Returns a non-malleable `ExternalMatchResponse`
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_external_match(
         &self,
         order: ExternalOrder,
@@ -136,6 +145,7 @@ impl ExternalMatchClient {
     /// Request an external match with options (v1 API)
     ///
     /// Returns a non-malleable `ExternalMatchResponse`
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_external_match_with_options(
         &self,
         order: ExternalOrder,
@@ -1,9 +1,15 @@
 //! HTTP client for connecting to the relayer
 
-use crate::auth::{HmacKey, add_expiring_auth_to_headers};
+use crate::{
+    auth::{HmacKey, add_expiring_auth_to_headers},
+    telemetry,
+};
 use reqwest::{Client, header::HeaderMap};
 use serde::{Serialize, de::DeserializeOwned};
-use std::time::Duration;
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
 use url::Url;
 
 /// The duration for which request signatures are valid
@@ -44,7 +50,7 @@ impl From<reqwest::Error> for RelayerHttpClientError {
 }
 
 /// An HTTP client for connecting to the relayer
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct RelayerHttpClient {
     /// The HTTP client
     client: Client,
@@ -54,6 +60,15 @@ pub struct RelayerHttpClient {
     auth_key: HmacKey,
 }
 
+impl fmt::Debug for RelayerHttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelayerHttpClient")
+            .field("base_url", &self.base_url)
+            .field("auth_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
 #[allow(unused)]
 impl RelayerHttpClient {
     /// Create a new HTTP client
@@ -120,6 +135,10 @@ impl RelayerHttpClient {
 
     /// Send a POST request with custom headers to the relayer and return raw
     /// response
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(method = "POST", route = telemetry::route_of(path)))
+    )]
     pub async fn post_with_headers_raw<Req: Serialize>(
         &self,
         path: &str,
@@ -132,12 +151,19 @@ impl RelayerHttpClient {
         let body_bytes = serde_json::to_vec(&body).unwrap();
         self.add_headers(&url, &mut custom_headers, &body_bytes);
 
-        let raw = self.client.post(url).headers(custom_headers).body(body_bytes).send().await?;
-        Ok(raw)
+        let start = Instant::now();
+        let res = self.client.post(url).headers(custom_headers).body(body_bytes).send().await;
+        telemetry::record_http_response("POST", path, &res, start.elapsed());
+
+        Ok(res?)
     }
 
     /// Send a GET request with custom headers to the relayer and return raw
     /// response
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(method = "GET", route = telemetry::route_of(path)))
+    )]
     pub async fn get_with_headers_raw(
         &self,
         path: &str,
@@ -148,8 +174,11 @@ impl RelayerHttpClient {
 
         self.add_headers(&url, &mut custom_headers, &[]);
 
-        let raw = self.client.get(url).headers(custom_headers).send().await?;
-        Ok(raw)
+        let start = Instant::now();
+        let res = self.client.get(url).headers(custom_headers).send().await;
+        telemetry::record_http_response("GET", path, &res, start.elapsed());
+
+        Ok(res?)
     }
 
     // -----------
@@ -5,13 +5,25 @@
 #![deny(clippy::needless_pass_by_ref_mut)]
 
 pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 #[cfg(feature = "external-match-client")]
 pub(crate) mod external_match_client;
 mod http;
+pub mod network;
+#[cfg(feature = "settings")]
+pub mod settings;
+mod telemetry;
 pub mod types;
 mod util;
 
 pub use auth::HmacKey;
+pub use network::{
+    ARBITRUM_ONE_CHAIN_ID, ARBITRUM_SEPOLIA_CHAIN_ID, BASE_MAINNET_CHAIN_ID, BASE_SEPOLIA_CHAIN_ID,
+    ETHEREUM_SEPOLIA_CHAIN_ID, Network, NetworkConfig, NetworkError,
+};
+#[cfg(feature = "settings")]
+pub use settings::{ClientSettings, ConfigError, KeySource};
 
 #[cfg(feature = "internal")]
 pub use http::*;
@@ -31,34 +43,3 @@ pub use alloy_rpc_types_eth::TransactionRequest;
 
 #[cfg(feature = "examples")]
 pub mod example_utils;
-
-// --- Relayer URLs --- //
-
-/// The Arbitrum Sepolia relayer base URL
-pub(crate) const ARBITRUM_SEPOLIA_RELAYER_BASE_URL: &str =
-    "https://arbitrum-sepolia.v2.relayer.renegade.fi";
-/// The Arbitrum One relayer base URL
-pub(crate) const ARBITRUM_ONE_RELAYER_BASE_URL: &str =
-    "https://arbitrum-one.v2.relayer.renegade.fi";
-/// The Base Sepolia relayer base URL                                          
-pub(crate) const BASE_SEPOLIA_RELAYER_BASE_URL: &str =
-    "https://base-sepolia.v2.relayer.renegade.fi";
-/// The Base mainnet relayer base URL                                          
-pub(crate) const BASE_MAINNET_RELAYER_BASE_URL: &str =
-    "https://base-mainnet.v2.relayer.renegade.fi";
-/// The Ethereum Sepolia relayer base URL
-pub(crate) const ETHEREUM_SEPOLIA_RELAYER_BASE_URL: &str =
-    "https://ethereum-sepolia.v2.relayer.renegade.fi";
-
-// --- Chain IDs --- //
-
-/// The Arbitrum one chain ID
-pub const ARBITRUM_ONE_CHAIN_ID: u64 = 42161;
-/// The Arbitrum Sepolia chain ID
-pub const ARBITRUM_SEPOLIA_CHAIN_ID: u64 = 421614;
-/// The Base mainnet chain ID
-pub const BASE_MAINNET_CHAIN_ID: u64 = 8453;
-/// The Base Sepolia chain ID
-pub const BASE_SEPOLIA_CHAIN_ID: u64 = 84532;
-/// The Ethereum Sepolia chain ID
-pub const ETHEREUM_SEPOLIA_CHAIN_ID: u64 = 11155111;
@@ -0,0 +1,434 @@
+//! Parameters for the networks Renegade is deployed on
+//!
+//! [`Network`] enumerates the hosted deployments, and [`NetworkConfig`] holds
+//! the URLs and contract addresses the clients need to talk to one. Use
+//! [`NetworkConfig::custom`] to point the clients at a local devnet or
+//! self-hosted relayer.
+
+use std::{fmt, str::FromStr};
+
+use alloy::primitives::{Address, address};
+
+// -------------
+// | Constants |
+// -------------
+
+// --- Chain IDs --- //
+
+/// The Arbitrum one chain ID
+pub const ARBITRUM_ONE_CHAIN_ID: u64 = 42161;
+/// The Arbitrum Sepolia chain ID
+pub const ARBITRUM_SEPOLIA_CHAIN_ID: u64 = 421614;
+/// The Base mainnet chain ID
+pub const BASE_MAINNET_CHAIN_ID: u64 = 8453;
+/// The Base Sepolia chain ID
+pub const BASE_SEPOLIA_CHAIN_ID: u64 = 84532;
+/// The Ethereum Sepolia chain ID
+pub const ETHEREUM_SEPOLIA_CHAIN_ID: u64 = 11155111;
+
+// --- Chain Names --- //
+
+/// The environment-agnostic name of the Arbitrum chains
+const ARBITRUM_CHAIN_NAME: &str = "arbitrum";
+/// The environment-agnostic name of the Base chains
+const BASE_CHAIN_NAME: &str = "base";
+/// The environment-agnostic name of the Ethereum chains
+const ETHEREUM_CHAIN_NAME: &str = "ethereum";
+/// The default chain name of a custom network
+const DEFAULT_CUSTOM_CHAIN_NAME: &str = "devnet";
+
+// --- Relayer URLs --- //
+
+/// The Arbitrum Sepolia relayer base URL
+const ARBITRUM_SEPOLIA_RELAYER_BASE_URL: &str = "https://arbitrum-sepolia.v2.relayer.renegade.fi";
+/// The Arbitrum One relayer base URL
+const ARBITRUM_ONE_RELAYER_BASE_URL: &str = "https://arbitrum-one.v2.relayer.renegade.fi";
+/// The Base Sepolia relayer base URL
+const BASE_SEPOLIA_RELAYER_BASE_URL: &str = "https://base-sepolia.v2.relayer.renegade.fi";
+/// The Base mainnet relayer base URL
+const BASE_MAINNET_RELAYER_BASE_URL: &str = "https://base-mainnet.v2.relayer.renegade.fi";
+/// The Ethereum Sepolia relayer base URL
+const ETHEREUM_SEPOLIA_RELAYER_BASE_URL: &str = "https://ethereum-sepolia.v2.relayer.renegade.fi";
+
+// --- Auth Server URLs --- //
+
+/// The Arbitrum Sepolia auth server base URL
+const ARBITRUM_SEPOLIA_AUTH_BASE_URL: &str = "https://arbitrum-sepolia.v2.auth-server.renegade.fi";
+/// The Arbitrum One auth server base URL
+const ARBITRUM_ONE_AUTH_BASE_URL: &str = "https://arbitrum-one.v2.auth-server.renegade.fi";
+/// The Base Sepolia auth server base URL
+const BASE_SEPOLIA_AUTH_BASE_URL: &str = "https://base-sepolia.v2.auth-server.renegade.fi";
+/// The Base mainnet auth server base URL
+const BASE_MAINNET_AUTH_BASE_URL: &str = "https://base-mainnet.v2.auth-server.renegade.fi";
+/// The Ethereum Sepolia auth server base URL
+const ETHEREUM_SEPOLIA_AUTH_BASE_URL: &str = "https://ethereum-sepolia.v2.auth-server.renegade.fi";
+
+// --- Historical State URLs --- //
+
+/// The mainnet historical state base URL
+const MAINNET_HISTORICAL_STATE_BASE_URL: &str = "https://mainnet.historical-state.renegade.fi";
+/// The testnet historical state base URL
+const TESTNET_HISTORICAL_STATE_BASE_URL: &str = "https://testnet.historical-state.renegade.fi";
+
+// --- Darkpool Addresses --- //
+
+/// The darkpool address on Arbitrum One
+const ARBITRUM_ONE_DARKPOOL_ADDRESS: Address =
+    address!("0xC5D1B8096BbdEC83Bc6049e42822c7483BBA6500");
+/// The darkpool address on Arbitrum Sepolia
+const ARBITRUM_SEPOLIA_DARKPOOL_ADDRESS: Address =
+    address!("0x57dF3a4449aaBf72f61e4A5DFe83d4A45DcC8537");
+/// The darkpool address on Base Mainnet
+const BASE_MAINNET_DARKPOOL_ADDRESS: Address =
+    address!("0x15d7CF277BE6463F153Dd0d4d73F92Ad65e6348C");
+/// The darkpool address on Base Sepolia
+const BASE_SEPOLIA_DARKPOOL_ADDRESS: Address =
+    address!("0xDE9BfD62B2187d4c14FBcC7D869920d34e4DB3Da");
+/// The darkpool address on Ethereum Sepolia
+const ETHEREUM_SEPOLIA_DARKPOOL_ADDRESS: Address =
+    address!("0x45537c28F245645CC1E7F7258FCC18A189CE16e3");
+
+// --- Permit2 Addresses --- //
+
+/// The canonical permit2 address, shared by all networks except Arbitrum
+/// Sepolia
+pub(crate) const CANONICAL_PERMIT2_ADDRESS: Address =
+    address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
+/// The permit2 address on Arbitrum Sepolia
+const ARBITRUM_SEPOLIA_PERMIT2_ADDRESS: Address =
+    address!("0x9458198bcc289c42e460cb8ca143e5854f734442");
+
+// --- WETH Addresses --- //
+
+/// The WETH address on Arbitrum One
+const ARBITRUM_ONE_WETH_ADDRESS: Address = address!("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1");
+/// The WETH address on Arbitrum Sepolia
+const ARBITRUM_SEPOLIA_WETH_ADDRESS: Address =
+    address!("0x980B62Da83eFf3D4576C647993b0c1D7faf17c73");
+/// The WETH predeploy address on Base Mainnet & Base Sepolia
+const BASE_WETH_ADDRESS: Address = address!("0x4200000000000000000000000000000000000006");
+/// The WETH address on Ethereum Sepolia
+const ETHEREUM_SEPOLIA_WETH_ADDRESS: Address =
+    address!("0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14");
+
+// --- Executor Addresses --- //
+
+/// The executor address on Arbitrum One
+const ARBITRUM_ONE_EXECUTOR_ADDRESS: Address =
+    address!("0x336A6b8AE5589d40ba4391020649E268E8323CA1");
+/// The executor address on Arbitrum Sepolia
+const ARBITRUM_SEPOLIA_EXECUTOR_ADDRESS: Address =
+    address!("0x9094314D60e3eF5fC73df548A3dD7b1Cd9798729");
+/// The executor address on Base Mainnet
+const BASE_MAINNET_EXECUTOR_ADDRESS: Address =
+    address!("0x1b5A1833d8566FACb138aa6BF1cd040f572B1D56");
+/// The executor address on Base Sepolia
+const BASE_SEPOLIA_EXECUTOR_ADDRESS: Address =
+    address!("0x5E2ca57B7F09Cf3DAca07c67CC65e1BfbDf346b0");
+/// The executor address on Ethereum Sepolia
+const ETHEREUM_SEPOLIA_EXECUTOR_ADDRESS: Address =
+    address!("0x92467D2FF278383187f0aB04F8511EF45c31b723");
+
+// --- Relayer Fee Recipient Addresses --- //
+
+/// The relayer fee recipient address on Arbitrum Sepolia
+const ARBITRUM_SEPOLIA_RELAYER_FEE_RECIPIENT: Address =
+    address!("0xb0c0d3e8ebc39df5799d9c98d65dacf8637deba1");
+/// The relayer fee recipient address on Base Sepolia
+const BASE_SEPOLIA_RELAYER_FEE_RECIPIENT: Address =
+    address!("0xa125ecd644591348d08243d8821120c6d7d3a077");
+
+// ---------
+// | Error |
+// ---------
+
+/// An error looking up a network
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NetworkError {
+    /// No hosted network exists for the given chain ID
+    #[error("unsupported chain ID: {0}")]
+    UnsupportedChainId(u64),
+    /// No hosted network exists with the given name
+    #[error("unknown network: {0}")]
+    UnknownNetwork(String),
+}
+
+// -----------
+// | Network |
+// -----------
+
+/// A hosted Renegade deployment
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// Arbitrum One
+    ArbitrumOne,
+    /// Arbitrum Sepolia
+    ArbitrumSepolia,
+    /// Base mainnet
+    BaseMainnet,
+    /// Base Sepolia
+    BaseSepolia,
+    /// Ethereum Sepolia
+    EthereumSepolia,
+}
+
+impl Network {
+    /// All hosted networks
+    pub const ALL: [Network; 5] = [
+        Network::ArbitrumOne,
+        Network::ArbitrumSepolia,
+        Network::BaseMainnet,
+        Network::BaseSepolia,
+        Network::EthereumSepolia,
+    ];
+
+    /// Look up the hosted network with the given chain ID
+    pub fn from_chain_id(chain_id: u64) -> Result<Self, NetworkError> {
+        Self::ALL
+            .into_iter()
+            .find(|network| network.chain_id() == chain_id)
+            .ok_or(NetworkError::UnsupportedChainId(chain_id))
+    }
+
+    /// The network's chain ID
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Network::ArbitrumOne => ARBITRUM_ONE_CHAIN_ID,
+            Network::ArbitrumSepolia => ARBITRUM_SEPOLIA_CHAIN_ID,
+            Network::BaseMainnet => BASE_MAINNET_CHAIN_ID,
+            Network::BaseSepolia => BASE_SEPOLIA_CHAIN_ID,
+            Network::EthereumSepolia => ETHEREUM_SEPOLIA_CHAIN_ID,
+        }
+    }
+
+    /// Whether the network is a mainnet deployment
+    pub fn is_mainnet(&self) -> bool {
+        matches!(self, Network::ArbitrumOne | Network::BaseMainnet)
+    }
+
+    /// The network's name, as accepted by [`Network::from_str`]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Network::ArbitrumOne => "arbitrum-one",
+            Network::ArbitrumSepolia => "arbitrum-sepolia",
+            Network::BaseMainnet => "base-mainnet",
+            Network::BaseSepolia => "base-sepolia",
+            Network::EthereumSepolia => "ethereum-sepolia",
+        }
+    }
+
+    /// The parameters of the network
+    pub fn config(&self) -> NetworkConfig {
+        let historical_state_base_url = if self.is_mainnet() {
+            MAINNET_HISTORICAL_STATE_BASE_URL
+        } else {
+            TESTNET_HISTORICAL_STATE_BASE_URL
+        };
+
+        let (chain_name, relayer_base_url, auth_base_url) = match self {
+            Network::ArbitrumOne => {
+                (ARBITRUM_CHAIN_NAME, ARBITRUM_ONE_RELAYER_BASE_URL, ARBITRUM_ONE_AUTH_BASE_URL)
+            },
+            Network::ArbitrumSepolia => (
+                ARBITRUM_CHAIN_NAME,
+                ARBITRUM_SEPOLIA_RELAYER_BASE_URL,
+                ARBITRUM_SEPOLIA_AUTH_BASE_URL,
+            ),
+            Network::BaseMainnet => {
+                (BASE_CHAIN_NAME, BASE_MAINNET_RELAYER_BASE_URL, BASE_MAINNET_AUTH_BASE_URL)
+            },
+            Network::BaseSepolia => {
+                (BASE_CHAIN_NAME, BASE_SEPOLIA_RELAYER_BASE_URL, BASE_SEPOLIA_AUTH_BASE_URL)
+            },
+            Network::EthereumSepolia => (
+                ETHEREUM_CHAIN_NAME,
+                ETHEREUM_SEPOLIA_RELAYER_BASE_URL,
+                ETHEREUM_SEPOLIA_AUTH_BASE_URL,
+            ),
+        };
+
+        let (darkpool_address, permit2_address, executor_address, relayer_fee_recipient) =
+            match self {
+                Network::ArbitrumOne => (
+                    ARBITRUM_ONE_DARKPOOL_ADDRESS,
+                    CANONICAL_PERMIT2_ADDRESS,
+                    ARBITRUM_ONE_EXECUTOR_ADDRESS,
+                    Address::ZERO,
+                ),
+                Network::ArbitrumSepolia => (
+                    ARBITRUM_SEPOLIA_DARKPOOL_ADDRESS,
+                    ARBITRUM_SEPOLIA_PERMIT2_ADDRESS,
+                    ARBITRUM_SEPOLIA_EXECUTOR_ADDRESS,
+                    ARBITRUM_SEPOLIA_RELAYER_FEE_RECIPIENT,
+                ),
+                Network::BaseMainnet => (
+                    BASE_MAINNET_DARKPOOL_ADDRESS,
+                    CANONICAL_PERMIT2_ADDRESS,
+                    BASE_MAINNET_EXECUTOR_ADDRESS,
+                    Address::ZERO,
+                ),
+                Network::BaseSepolia => (
+                    BASE_SEPOLIA_DARKPOOL_ADDRESS,
+                    CANONICAL_PERMIT2_ADDRESS,
+                    BASE_SEPOLIA_EXECUTOR_ADDRESS,
+                    BASE_SEPOLIA_RELAYER_FEE_RECIPIENT,
+                ),
+                Network::EthereumSepolia => (
+                    ETHEREUM_SEPOLIA_DARKPOOL_ADDRESS,
+                    CANONICAL_PERMIT2_ADDRESS,
+                    ETHEREUM_SEPOLIA_EXECUTOR_ADDRESS,
+                    Address::ZERO,
+                ),
+            };
+
+        let weth_address = match self {
+            Network::ArbitrumOne => ARBITRUM_ONE_WETH_ADDRESS,
+            Network::ArbitrumSepolia => ARBITRUM_SEPOLIA_WETH_ADDRESS,
+            Network::BaseMainnet | Network::BaseSepolia => BASE_WETH_ADDRESS,
+            Network::EthereumSepolia => ETHEREUM_SEPOLIA_WETH_ADDRESS,
+        };
+
+        NetworkConfig {
+            chain_id: self.chain_id(),
+            chain_name: chain_name.to_string(),
+            relayer_base_url: relayer_base_url.to_string(),
+            auth_base_url: auth_base_url.to_string(),
+            historical_state_base_url: historical_state_base_url.to_string(),
+            darkpool_address,
+            permit2_address,
+            executor_address,
+            relayer_fee_recipient,
+            weth_address,
+            // Arbitrum deploys the darkpool as a Stylus contract
+            is_solidity_chain: !matches!(self, Network::ArbitrumOne | Network::ArbitrumSepolia),
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Network {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|network| network.name() == s)
+            .ok_or_else(|| NetworkError::UnknownNetwork(s.to_string()))
+    }
+}
+
+impl From<Network> for NetworkConfig {
+    fn from(network: Network) -> Self {
+        network.config()
+    }
+}
+
+// ------------------
+// | Network Config |
+// ------------------
+
+/// The URLs and contract addresses of a Renegade deployment
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkConfig {
+    /// The chain ID
+    pub chain_id: u64,
+    /// The environment-agnostic chain name, e.g. `arbitrum` for both Arbitrum
+    /// One and Arbitrum Sepolia
+    pub chain_name: String,
+    /// The relayer base URL
+    pub relayer_base_url: String,
+    /// The auth server base URL, used by the external match client
+    pub auth_base_url: String,
+    /// The historical state base URL
+    pub historical_state_base_url: String,
+    /// The darkpool contract address
+    pub darkpool_address: Address,
+    /// The permit2 contract address
+    pub permit2_address: Address,
+    /// The relayer's executor address
+    pub executor_address: Address,
+    /// The relayer's fee recipient address
+    pub relayer_fee_recipient: Address,
+    /// The wrapped ether contract address, used to deposit & withdraw native
+    /// ETH
+    pub weth_address: Address,
+    /// Whether the darkpool is deployed as a solidity contract
+    pub is_solidity_chain: bool,
+}
+
+impl NetworkConfig {
+    /// Look up the parameters of the hosted network with the given chain ID
+    pub fn from_chain_id(chain_id: u64) -> Result<Self, NetworkError> {
+        Network::from_chain_id(chain_id).map(|network| network.config())
+    }
+
+    /// Create the parameters of a custom deployment, e.g. a relayer running
+    /// against a local anvil devnet
+    ///
+    /// The auth server and historical state URLs default to the relayer URL,
+    /// the fee recipient & WETH addresses default to the zero address, and the
+    /// darkpool is assumed to be a solidity contract. Override these with the
+    /// `with_*` methods.
+    pub fn custom(
+        chain_id: u64,
+        relayer_base_url: &str,
+        darkpool_address: Address,
+        permit2_address: Address,
+        executor_address: Address,
+    ) -> Self {
+        Self {
+            chain_id,
+            chain_name: DEFAULT_CUSTOM_CHAIN_NAME.to_string(),
+            relayer_base_url: relayer_base_url.to_string(),
+            auth_base_url: relayer_base_url.to_string(),
+            historical_state_base_url: relayer_base_url.to_string(),
+            darkpool_address,
+            permit2_address,
+            executor_address,
+            relayer_fee_recipient: Address::ZERO,
+            weth_address: Address::ZERO,
+            is_solidity_chain: true,
+        }
+    }
+
+    /// Set the environment-agnostic chain name
+    pub fn with_chain_name(mut self, chain_name: &str) -> Self {
+        self.chain_name = chain_name.to_string();
+        self
+    }
+
+    /// Set the auth server base URL
+    pub fn with_auth_base_url(mut self, auth_base_url: &str) -> Self {
+        self.auth_base_url = auth_base_url.to_string();
+        self
+    }
+
+    /// Set the historical state base URL
+    pub fn with_historical_state_base_url(mut self, historical_state_base_url: &str) -> Self {
+        self.historical_state_base_url = historical_state_base_url.to_string();
+        self
+    }
+
+    /// Set the relayer's fee recipient address
+    pub fn with_relayer_fee_recipient(mut self, relayer_fee_recipient: Address) -> Self {
+        self.relayer_fee_recipient = relayer_fee_recipient;
+        self
+    }
+
+    /// Set the wrapped ether contract address
+    pub fn with_weth_address(mut self, weth_address: Address) -> Self {
+        self.weth_address = weth_address;
+        self
+    }
+
+    /// Set whether the darkpool is deployed as a solidity contract
+    pub fn with_solidity_chain(mut self, is_solidity_chain: bool) -> Self {
+        self.is_solidity_chain = is_solidity_chain;
+        self
+    }
+}
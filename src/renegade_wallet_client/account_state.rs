@@ -0,0 +1,278 @@
+//! An in-memory mirror of an account's balances & orders
+//!
+//! [`AccountState`] loads a snapshot of the account over HTTP, then keeps it up
+//! to date by applying the balance, order, and fill updates streamed over the
+//! websocket. Messages may be missed while the websocket reconnects, or dropped
+//! if the mirror falls behind the topic channels, so the snapshot is reloaded
+//! whenever either happens.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use alloy::primitives::Address;
+use futures_util::{Stream, StreamExt, stream::select_all};
+use renegade_external_api::types::{
+    ApiBalance, ApiOrder,
+    websocket::{FillMessage, ServerWebsocketMessageBody},
+};
+use tokio::{
+    sync::{RwLock, broadcast, watch},
+    task::JoinHandle,
+};
+use tokio_stream::wrappers::{BroadcastStream, errors::BroadcastStreamRecvError};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{RenegadeClientError, view_client::RenegadeViewClient};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The number of recent fills retained in the snapshot
+const MAX_RECENT_FILLS: usize = 1000;
+/// The capacity of the change notification channel
+const CHANGES_CHANNEL_CAPACITY: usize = 100;
+/// The delay between attempts to reload a snapshot after a failure
+const RESYNC_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+// ---------
+// | Types |
+// ---------
+
+/// A point-in-time copy of the account's state
+#[derive(Clone, Debug, Default)]
+pub struct AccountSnapshot {
+    /// The account's balances, keyed by mint
+    pub balances: HashMap<Address, ApiBalance>,
+    /// The account's orders, keyed by order ID
+    ///
+    /// Holds the orders that were open when the snapshot was last reloaded,
+    /// along with any orders updated since
+    pub orders: HashMap<Uuid, ApiOrder>,
+    /// The fills received since the mirror was created, oldest first
+    pub recent_fills: VecDeque<FillMessage>,
+}
+
+/// A change applied to the account's state
+#[derive(Clone, Debug)]
+pub enum AccountChange {
+    /// A balance was updated
+    Balance(ApiBalance),
+    /// An order was updated
+    Order(ApiOrder),
+    /// An order was filled
+    Fill(FillMessage),
+    /// The snapshot was reloaded from the relayer, replacing the balances &
+    /// orders wholesale
+    Resynced,
+}
+
+// -----------------
+// | Account State |
+// -----------------
+
+/// A handle to an in-memory mirror of an account's state
+///
+/// Cloned handles share the same mirror. The mirror stops updating once all
+/// handles are dropped.
+#[derive(Clone)]
+pub struct AccountState {
+    /// The state shared with the sync task
+    shared: Arc<SharedState>,
+    /// The task applying websocket updates to the snapshot
+    _sync_task: Arc<SyncTask>,
+}
+
+/// The state shared between handles & the sync task
+struct SharedState {
+    /// The client used to load snapshots
+    client: RenegadeViewClient,
+    /// The current snapshot
+    snapshot: RwLock<AccountSnapshot>,
+    /// The channel on which changes are broadcast
+    changes_tx: broadcast::Sender<AccountChange>,
+}
+
+/// A handle to the sync task, which aborts the task when dropped
+struct SyncTask(JoinHandle<()>);
+
+impl Drop for SyncTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl AccountState {
+    /// Load the account's state & begin applying updates to it
+    pub async fn new(client: RenegadeViewClient) -> Result<Self, RenegadeClientError> {
+        // Subscribe before loading the snapshot, so that no update is missed
+        // between the two
+        let updates = subscribe_account_updates(&client).await?;
+        let reconnects = client.websocket_client.watch_reconnects();
+
+        let (changes_tx, _) = broadcast::channel(CHANGES_CHANNEL_CAPACITY);
+        let shared = Arc::new(SharedState {
+            client,
+            snapshot: RwLock::new(AccountSnapshot::default()),
+            changes_tx,
+        });
+        shared.resync().await?;
+
+        let task = tokio::spawn(shared.clone().run_sync_loop(updates, reconnects));
+        Ok(Self { shared, _sync_task: Arc::new(SyncTask(task)) })
+    }
+
+    // --- Reads --- //
+
+    /// Get a consistent copy of the account's state
+    pub async fn snapshot(&self) -> AccountSnapshot {
+        self.shared.snapshot.read().await.clone()
+    }
+
+    /// Get all balances in the account
+    pub async fn balances(&self) -> Vec<ApiBalance> {
+        self.shared.snapshot.read().await.balances.values().cloned().collect()
+    }
+
+    /// Get the account's balance for a given mint
+    pub async fn balance(&self, mint: Address) -> Option<ApiBalance> {
+        self.shared.snapshot.read().await.balances.get(&mint).cloned()
+    }
+
+    /// Get all orders in the account
+    pub async fn orders(&self) -> Vec<ApiOrder> {
+        self.shared.snapshot.read().await.orders.values().cloned().collect()
+    }
+
+    /// Get an order by its ID
+    pub async fn order(&self, order_id: Uuid) -> Option<ApiOrder> {
+        self.shared.snapshot.read().await.orders.get(&order_id).cloned()
+    }
+
+    // --- Changes --- //
+
+    /// Stream the changes applied to the account's state
+    ///
+    /// Changes applied before the stream is created are not replayed. A
+    /// consumer that falls behind the stream skips the changes it missed.
+    pub fn changes(&self) -> impl Stream<Item = AccountChange> + use<> {
+        BroadcastStream::new(self.shared.changes_tx.subscribe())
+            .filter_map(|change| async move { change.ok() })
+    }
+
+    /// Reload the snapshot from the relayer
+    pub async fn resync(&self) -> Result<(), RenegadeClientError> {
+        self.shared.resync().await
+    }
+}
+
+// -------------
+// | Sync Loop |
+// -------------
+
+impl SharedState {
+    /// Apply websocket updates to the snapshot, reloading it after a
+    /// reconnection or after updates are dropped
+    async fn run_sync_loop(
+        self: Arc<Self>,
+        mut updates: impl Stream<Item = Result<ServerWebsocketMessageBody, BroadcastStreamRecvError>>
+        + Unpin,
+        mut reconnects: watch::Receiver<u64>,
+    ) {
+        loop {
+            tokio::select! {
+                update = updates.next() => match update {
+                    Some(Ok(message)) => self.apply(message).await,
+                    Some(Err(BroadcastStreamRecvError::Lagged(n))) => {
+                        warn!("Account state missed {n} updates, resyncing");
+                        self.resync_until_success().await;
+                    },
+                    None => break,
+                },
+                changed = reconnects.changed() => match changed {
+                    Ok(()) => self.resync_until_success().await,
+                    Err(_) => break,
+                },
+            }
+        }
+    }
+
+    /// Apply a websocket update to the snapshot
+    async fn apply(&self, message: ServerWebsocketMessageBody) {
+        let change = {
+            let mut snapshot = self.snapshot.write().await;
+            match message {
+                ServerWebsocketMessageBody::BalanceUpdate(update) => {
+                    let balance = update.balance;
+                    snapshot.balances.insert(balance.mint, balance.clone());
+                    AccountChange::Balance(balance)
+                },
+                ServerWebsocketMessageBody::OrderUpdate(update) => {
+                    let order = update.order;
+                    snapshot.orders.insert(order.order.id, order.clone());
+                    AccountChange::Order(order)
+                },
+                ServerWebsocketMessageBody::Fill(fill) => {
+                    if snapshot.recent_fills.len() == MAX_RECENT_FILLS {
+                        snapshot.recent_fills.pop_front();
+                    }
+                    snapshot.recent_fills.push_back(fill.clone());
+                    AccountChange::Fill(fill)
+                },
+                _ => return,
+            }
+        };
+
+        // There may be no listeners, which is not an error
+        let _ = self.changes_tx.send(change);
+    }
+
+    /// Reload the snapshot, retrying until it succeeds
+    async fn resync_until_success(&self) {
+        while let Err(e) = self.resync().await {
+            warn!("Failed to resync account state: {e}");
+            tokio::time::sleep(RESYNC_RETRY_DELAY).await;
+        }
+    }
+
+    /// Reload the account's balances & open orders from the relayer
+    async fn resync(&self) -> Result<(), RenegadeClientError> {
+        let balances = self.client.get_balances().await?;
+        let orders = self.client.get_orders(false).await?;
+
+        {
+            let mut snapshot = self.snapshot.write().await;
+            snapshot.balances = balances.into_iter().map(|b| (b.mint, b)).collect();
+            snapshot.orders = orders.into_iter().map(|o| (o.order.id, o)).collect();
+        }
+
+        let _ = self.changes_tx.send(AccountChange::Resynced);
+        Ok(())
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Subscribe to the account's balance, order, and fill topics as one stream
+///
+/// Unlike the typed subscriptions on the websocket client, the stream surfaces
+/// lag errors, so that dropped updates can be detected
+async fn subscribe_account_updates(
+    client: &RenegadeViewClient,
+) -> Result<
+    impl Stream<Item = Result<ServerWebsocketMessageBody, BroadcastStreamRecvError>> + Unpin + use<>,
+    RenegadeClientError,
+> {
+    let ws = &client.websocket_client;
+    let balances = ws.subscribe_to_topic(ws.balances_topic()).await?;
+    let orders = ws.subscribe_to_topic(ws.orders_topic()).await?;
+    let fills = ws.subscribe_to_topic(ws.fills_topic()).await?;
+
+    Ok(select_all([balances, orders, fills]))
+}
@@ -1,5 +1,6 @@
 //! Admin action to assign an order to a matching pool
 
+use alloy::signers::Signer;
 use renegade_external_api::{
     EmptyRequestResponse,
     http::admin::{ADMIN_ASSIGN_ORDER_TO_POOL_ROUTE, AssignOrderToPoolRequest},
@@ -8,7 +9,7 @@ use uuid::Uuid;
 
 use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
 
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Assigns an order to a specific matching pool via the admin API.
     ///
     /// This is an admin action that requires the client to be configured with
@@ -1,11 +1,12 @@
 //! Admin action to create a matching pool
 
+use alloy::signers::Signer;
 use renegade_external_api::EmptyRequestResponse;
 use renegade_external_api::http::admin::ADMIN_MATCHING_POOL_CREATE_ROUTE;
 
 use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
 
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Creates a new matching pool via the admin API.
     ///
     /// Orders can only be matched with other orders in the same matching pool.
@@ -1,5 +1,6 @@
 //! Fetches all orders for a given account (admin)
 
+use alloy::signers::Signer;
 use renegade_external_api::http::admin::ADMIN_GET_ACCOUNT_ORDERS_ROUTE;
 use renegade_external_api::types::{ApiAdminOrder, GetOrdersAdminResponse};
 use uuid::Uuid;
@@ -11,7 +12,7 @@ use crate::{
 };
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Fetches all orders for the given account (admin).
     ///
     /// This method will paginate through all of the account's orders across
@@ -42,7 +43,7 @@ impl RenegadeClient {
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Builds the request path for the admin get account orders endpoint
     fn build_admin_get_account_orders_request_path(
         account_id: Uuid,
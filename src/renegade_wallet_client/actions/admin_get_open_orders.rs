@@ -1,5 +1,6 @@
 //! Fetches all open orders managed by the relayer
 
+use alloy::signers::Signer;
 use renegade_external_api::http::admin::ADMIN_GET_ORDERS_ROUTE;
 use renegade_external_api::types::{ApiAdminOrder, GetOrdersAdminResponse};
 
@@ -10,7 +11,7 @@ use crate::{
 };
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Fetches all open orders managed by the relayer.
     ///
     /// This method will paginate through all of the orders across multiple
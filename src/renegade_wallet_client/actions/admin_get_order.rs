@@ -1,5 +1,6 @@
 //! Fetch a given order managed by the relayer by its ID
 
+use alloy::signers::Signer;
 use renegade_external_api::http::admin::ADMIN_GET_ORDER_BY_ID_ROUTE;
 use renegade_external_api::types::{ApiAdminOrder, GetOrderAdminResponse};
 use uuid::Uuid;
@@ -7,7 +8,7 @@ use uuid::Uuid;
 use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Look up an order by its ID
     pub async fn admin_get_order(
         &self,
@@ -1,12 +1,13 @@
 //! Check if an account's task queue is paused (admin)
 
+use alloy::signers::Signer;
 use renegade_external_api::http::admin::ADMIN_GET_TASK_QUEUE_PAUSED_ROUTE;
 use renegade_external_api::types::TaskQueuePausedResponse;
 use uuid::Uuid;
 
 use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
 
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Check if the given account's task queue is paused
     pub async fn admin_is_task_queue_paused(
         &self,
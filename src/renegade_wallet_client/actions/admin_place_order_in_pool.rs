@@ -2,8 +2,8 @@
 
 use std::str::FromStr;
 
-use alloy::primitives::Address;
-use renegade_circuit_types::{Amount, fixed_point::FixedPoint};
+use alloy::{primitives::Address, signers::Signer};
+use renegade_circuit_types::Amount;
 use renegade_external_api::{
     http::{
         admin::ADMIN_CREATE_ORDER_IN_POOL_ROUTE,
@@ -15,14 +15,15 @@ use uuid::Uuid;
 
 use crate::{
     RenegadeClientError,
-    actions::{NON_BLOCKING_PARAM, construct_http_path},
+    actions::{NON_BLOCKING_PARAM, construct_http_path, place_order::compute_min_price},
     client::RenegadeClient,
+    pair::{Side, TradingPair},
     utils::unwrap_field,
     websocket::{DEFAULT_TASK_TIMEOUT, TaskWaiter},
 };
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Create a new admin order builder with the client's account address as
     /// the owner
     pub fn new_admin_order_builder(&self) -> AdminOrderBuilder {
@@ -38,13 +39,9 @@ impl RenegadeClient {
         &self,
         built_order: BuiltAdminOrder,
     ) -> Result<(), RenegadeClientError> {
-        let admin_client = self.get_admin_client()?;
-
-        let request = self.build_admin_create_order_request(built_order).await?;
-
-        let path = self.build_admin_create_order_request_path(false)?;
-
-        admin_client.post::<_, CreateOrderResponse>(&path, request).await?;
+        // Submit non-blocking so that the account's CSPRNGs are released as soon
+        // as the relayer accepts the order, then wait for the task
+        self.enqueue_admin_order_placement_in_pool(built_order).await?.await?;
 
         Ok(())
     }
@@ -59,13 +56,8 @@ impl RenegadeClient {
         &self,
         built_order: BuiltAdminOrder,
     ) -> Result<TaskWaiter, RenegadeClientError> {
-        let admin_client = self.get_admin_client()?;
-
-        let request = self.build_admin_create_order_request(built_order).await?;
-
-        let path = self.build_admin_create_order_request_path(true)?;
-
-        let CreateOrderResponse { task_id, .. } = admin_client.post(&path, request).await?;
+        let CreateOrderResponse { task_id, .. } =
+            self.submit_admin_create_order_request(built_order, true).await?;
 
         // Create a task waiter for the task
         let task_waiter = self.watch_task(task_id, DEFAULT_TASK_TIMEOUT).await?;
@@ -75,19 +67,26 @@ impl RenegadeClient {
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
-    /// Builds the admin order creation request from the given built admin order
-    async fn build_admin_create_order_request(
+impl<S: Signer> RenegadeClient<S> {
+    /// Authorizes the given admin order & submits the order creation request
+    async fn submit_admin_create_order_request(
         &self,
         built_order: BuiltAdminOrder,
-    ) -> Result<CreateOrderInPoolRequest, RenegadeClientError> {
-        let auth = self.build_order_auth(&built_order.order).await?;
-
-        Ok(CreateOrderInPoolRequest {
-            order: built_order.order,
-            auth,
-            matching_pool: built_order.matching_pool,
+        non_blocking: bool,
+    ) -> Result<CreateOrderResponse, RenegadeClientError> {
+        let admin_client = self.get_admin_client()?;
+        let path = self.build_admin_create_order_request_path(non_blocking)?;
+        let BuiltAdminOrder { order, matching_pool, .. } = built_order;
+
+        self.with_order_auth(&order, async |auth| {
+            let request = CreateOrderInPoolRequest {
+                order: order.clone(),
+                auth,
+                matching_pool: matching_pool.clone(),
+            };
+            Ok(admin_client.post(&path, request).await?)
         })
+        .await
     }
 
     /// Builds the request path for the admin create order in pool endpoint
@@ -208,6 +207,33 @@ impl AdminOrderBuilder {
         self
     }
 
+    /// Set the order's mints & amounts from a side, size & limit price on a
+    /// trading pair
+    ///
+    /// E.g. buying 2 WETH at at most 3000 USDC per WETH spends at most 6000
+    /// USDC & receives at least 2 WETH. Amounts are rounded so that the order
+    /// never executes at a price worse than the limit.
+    ///
+    /// # Arguments
+    /// * `pair` - The trading pair, with the decimals of both tokens
+    /// * `side` - Whether to buy or sell the base token
+    /// * `base_size` - The amount of the base token to trade, in atoms
+    /// * `limit_price` - The limit price, in quote units per base unit
+    pub fn limit(
+        mut self,
+        pair: TradingPair,
+        side: Side,
+        base_size: Amount,
+        limit_price: f64,
+    ) -> Result<Self, RenegadeClientError> {
+        let params = pair.limit_order_params(side, base_size, limit_price)?;
+        self.input_mint = Some(params.input_mint);
+        self.output_mint = Some(params.output_mint);
+        self.amount_in = Some(params.amount_in);
+        self.min_output_amount = Some(params.min_output_amount);
+        Ok(self)
+    }
+
     /// Set the minimum fill size
     pub fn with_min_fill_size(mut self, min_fill: Amount) -> Self {
         self.min_fill_size = Some(min_fill);
@@ -242,12 +268,7 @@ impl AdminOrderBuilder {
     pub fn build(self) -> Result<BuiltAdminOrder, RenegadeClientError> {
         let amount_in = unwrap_field!(self, amount_in);
 
-        let min_output_amount: FixedPoint = self.min_output_amount.unwrap_or_default().into();
-        let min_price = if min_output_amount == FixedPoint::from(0u64) {
-            FixedPoint::from(0u64)
-        } else {
-            min_output_amount.ceil_div_int(amount_in).into()
-        };
+        let min_price = compute_min_price(self.min_output_amount.unwrap_or_default(), amount_in);
 
         let order = ApiOrderCore {
             id: self.id.unwrap_or_else(Uuid::new_v4),
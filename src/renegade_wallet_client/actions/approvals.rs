@@ -2,6 +2,7 @@
 
 use alloy::{
     primitives::{Address, U160, U256, aliases::U48},
+    signers::Signer,
     sol_types::SolCall,
 };
 use alloy_rpc_types_eth::{TransactionInput, TransactionRequest};
@@ -11,7 +12,7 @@ use crate::{
     renegade_wallet_client::utils::{IAllowanceTransfer, approveCall},
 };
 
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Build a transaction to approve the Permit2 contract as a spender for
     /// the given ERC20 token.
     ///
@@ -0,0 +1,73 @@
+//! Places & cancels orders in batches
+//!
+//! Batch actions pipeline their requests to the relayer, keeping a bounded
+//! number in flight at once. Private orders draw their seeds from the
+//! account's CSPRNGs while holding the seed manager's lock, so concurrent
+//! placements draw distinct seeds in the order the relayer accepts them.
+//!
+//! Each action returns one result per order, in the order the orders were
+//! given, so that a failure to place or cancel one order does not hide the
+//! outcome of the others.
+
+use alloy::signers::Signer;
+use futures_util::{StreamExt, stream};
+use renegade_external_api::types::ApiOrder;
+use uuid::Uuid;
+
+use crate::{
+    RenegadeClientError, actions::place_order::BuiltOrder, client::RenegadeClient,
+    websocket::TaskWaiter,
+};
+
+/// The maximum number of requests a batch action keeps in flight at once
+const MAX_IN_FLIGHT_REQUESTS: usize = 8;
+
+/// The outcome of a batch action: for each order ID, a `TaskWaiter` for the
+/// order's task, or the error that prevented it from being enqueued
+pub type BatchResult = Vec<(Uuid, Result<TaskWaiter, RenegadeClientError>)>;
+
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
+    /// Enqueues an order placement task for each of the given orders
+    pub async fn place_orders(&self, built_orders: Vec<BuiltOrder>) -> BatchResult {
+        stream::iter(built_orders)
+            .map(|built_order| async move {
+                let order_id = built_order.order.id;
+                (order_id, self.enqueue_order_placement(built_order).await)
+            })
+            .buffered(MAX_IN_FLIGHT_REQUESTS)
+            .collect()
+            .await
+    }
+
+    /// Enqueues an order cancellation task for each of the given orders
+    pub async fn cancel_orders(&self, order_ids: Vec<Uuid>) -> BatchResult {
+        stream::iter(order_ids)
+            .map(|order_id| async move {
+                (order_id, self.enqueue_order_cancellation(order_id).await)
+            })
+            .buffered(MAX_IN_FLIGHT_REQUESTS)
+            .collect()
+            .await
+    }
+
+    /// Enqueues an order cancellation task for each open order matching the
+    /// filter
+    ///
+    /// Returns an error only if the open orders cannot be fetched
+    pub async fn cancel_all_orders(
+        &self,
+        filter: impl Fn(&ApiOrder) -> bool,
+    ) -> Result<BatchResult, RenegadeClientError> {
+        let include_historic_orders = false;
+        let order_ids = self
+            .get_orders(include_historic_orders)
+            .await?
+            .into_iter()
+            .filter(|order| filter(order))
+            .map(|order| order.order.id)
+            .collect();
+
+        Ok(self.cancel_orders(order_ids).await)
+    }
+}
@@ -1,6 +1,6 @@
 //! Cancels an order in the wallet
 
-use alloy::primitives::U256;
+use alloy::{primitives::U256, signers::Signer};
 use renegade_darkpool_types::intent::DarkpoolStateIntent;
 use renegade_external_api::{
     http::order::{CANCEL_ORDER_ROUTE, CancelOrderRequest, CancelOrderResponse},
@@ -15,6 +15,7 @@ use crate::{
     RenegadeClientError,
     actions::{NON_BLOCKING_PARAM, construct_http_path},
     client::RenegadeClient,
+    renegade_wallet_client::signing::sign_with_nonce,
     websocket::{DEFAULT_TASK_TIMEOUT, TaskWaiter},
 };
 
@@ -22,21 +23,27 @@ use crate::{
 const CANCEL_DOMAIN: &[u8] = b"cancel";
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Cancels the order with the given ID. Waits for the order cancellation
     /// task to complete before returning.
+    ///
+    /// Removes the order's scheduled expiry, if any.
     pub async fn cancel_order(&self, order_id: Uuid) -> Result<(), RenegadeClientError> {
         let request = self.build_cancel_order_request(order_id).await?;
 
         let path = self.build_cancel_order_request_path(order_id, false)?;
 
         self.relayer_client.post::<_, CancelOrderResponse>(&path, request).await?;
+        self.remove_cancelled_order_expiry(order_id);
 
         Ok(())
     }
 
     /// Enqueues an order cancellation task in the relayer. Returns a
     /// `TaskWaiter` that can be used to await task completion.
+    ///
+    /// Removes the order's scheduled expiry, if any, once the relayer accepts
+    /// the cancellation.
     pub async fn enqueue_order_cancellation(
         &self,
         order_id: Uuid,
@@ -46,6 +53,7 @@ impl RenegadeClient {
         let path = self.build_cancel_order_request_path(order_id, true)?;
 
         let CancelOrderResponse { task_id, .. } = self.relayer_client.post(&path, request).await?;
+        self.remove_cancelled_order_expiry(order_id);
 
         // Create a task waiter for the task
         let task_waiter = self.watch_task(task_id, DEFAULT_TASK_TIMEOUT).await?;
@@ -55,7 +63,7 @@ impl RenegadeClient {
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Builds the order cancellation request from the given order ID
     async fn build_cancel_order_request(
         &self,
@@ -64,9 +72,9 @@ impl RenegadeClient {
         let (order, auth) = self.get_order_with_auth(order_id).await?;
 
         let cancel_signature = if let OrderAuth::PublicOrder { permit, intent_signature } = auth {
-            self.build_ring0_cancel_signature(permit, intent_signature)?
+            self.build_ring0_cancel_signature(permit, intent_signature).await?
         } else {
-            self.build_private_cancel_signature(order)?
+            self.build_private_cancel_signature(order).await?
         };
 
         Ok(CancelOrderRequest { cancel_signature })
@@ -93,7 +101,7 @@ impl RenegadeClient {
     /// The cancel digest is: sign over `"cancel" || intentNullifier` where
     /// `intentNullifier = keccak256(abi.encode(permit) ||
     /// originalIntentNonce)`.
-    fn build_ring0_cancel_signature(
+    async fn build_ring0_cancel_signature(
         &self,
         permit: ApiPublicIntentPermit,
         intent_signature: SignatureWithNonce,
@@ -113,8 +121,7 @@ impl RenegadeClient {
         let cancel_payload = [CANCEL_DOMAIN, nullifier_bytes.as_slice()].concat();
 
         // Sign: H(H(cancel_payload) || nonce || chainId)
-        let sol_sig = SolSignatureWithNonce::sign(&cancel_payload, chain_id, signer)
-            .map_err(RenegadeClientError::signing)?;
+        let sol_sig = sign_with_nonce(&cancel_payload, chain_id, signer).await?;
 
         Ok(sol_sig.into())
     }
@@ -122,8 +129,8 @@ impl RenegadeClient {
     /// Build the cancel signature for a ring1+ (private) order
     ///
     /// Ring1+ cancel: sign the Poseidon nullifier bytes, properly incorporating
-    /// nonce + chainId via `SignatureWithNonce::sign`.
-    fn build_private_cancel_signature(
+    /// nonce + chainId via `sign_with_nonce`.
+    async fn build_private_cancel_signature(
         &self,
         order: ApiOrder,
     ) -> Result<SignatureWithNonce, RenegadeClientError> {
@@ -134,8 +141,7 @@ impl RenegadeClient {
         let nullifier = intent.compute_nullifier();
         let nullifier_bytes = nullifier.to_bytes_be();
 
-        let sol_sig = SolSignatureWithNonce::sign(&nullifier_bytes, chain_id, signer)
-            .map_err(RenegadeClientError::signing)?;
+        let sol_sig = sign_with_nonce(&nullifier_bytes, chain_id, signer).await?;
 
         Ok(sol_sig.into())
     }
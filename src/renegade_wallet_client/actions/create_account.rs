@@ -1,5 +1,6 @@
 //! Create an account with the relayer
 
+use alloy::signers::Signer;
 use renegade_external_api::EmptyRequestResponse;
 use renegade_external_api::http::account::{CREATE_ACCOUNT_ROUTE, CreateAccountRequest};
 
@@ -8,23 +9,23 @@ use crate::{
     client::{AccountSecrets, RenegadeClient},
 };
 
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Create an account with the relayer.
     ///
     /// This method will register the account credentials with the relayer,
     /// but will not yet result in any state being committed onchain in the
     /// darkpool.
     pub async fn create_account(&self) -> Result<(), RenegadeClientError> {
-        let AccountSecrets { account_id, master_view_seed, auth_hmac_key, .. } = self.secrets;
+        let AccountSecrets { account_id, master_view_seed, auth_hmac_key, .. } = &self.secrets;
 
         let address = self.get_account_address();
         let schnorr_public_key = self.get_schnorr_public_key();
 
         let request = CreateAccountRequest {
-            account_id,
+            account_id: *account_id,
             address,
-            master_view_seed,
-            auth_hmac_key: auth_hmac_key.into(),
+            master_view_seed: *master_view_seed,
+            auth_hmac_key: auth_hmac_key.clone().into(),
             schnorr_public_key,
         };
 
@@ -1,6 +1,6 @@
 //! Deposit into an account balance
 
-use alloy::primitives::Address;
+use alloy::{primitives::Address, signers::Signer};
 use renegade_circuit_types::Amount;
 use renegade_crypto::fields::scalar_to_u256;
 use renegade_darkpool_types::balance::DarkpoolBalance;
@@ -9,27 +9,24 @@ use renegade_external_api::{
     http::balance::{DEPOSIT_BALANCE_ROUTE, DepositBalanceRequest, DepositBalanceResponse},
     types::ApiDepositPermit,
 };
-use renegade_solidity_abi::v2::{
-    IDarkpoolV2, relayer_types::u128_to_u256, transfer_auth::deposit::create_deposit_permit,
-};
+use renegade_solidity_abi::v2::{IDarkpoolV2, relayer_types::u128_to_u256};
 
 use crate::{
     RenegadeClientError,
     actions::{NON_BLOCKING_PARAM, construct_http_path},
     client::RenegadeClient,
+    renegade_wallet_client::signing::sign_deposit_permit,
     websocket::{DEFAULT_TASK_TIMEOUT, TaskWaiter},
 };
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Deposit funds into an account balance. Waits for the deposit task to
     /// complete before returning the post-deposit balance.
     pub async fn deposit(&self, mint: Address, amount: Amount) -> Result<(), RenegadeClientError> {
-        let request = self.build_deposit_request(mint, amount).await?;
-
-        let path = self.build_deposit_request_path(mint, false)?;
-
-        self.relayer_client.post::<_, DepositBalanceResponse>(&path, request).await?;
+        // Submit non-blocking so that the account's CSPRNGs are released as soon
+        // as the relayer accepts the deposit, then wait for the task
+        self.enqueue_deposit(mint, amount).await?.await?;
 
         Ok(())
     }
@@ -42,12 +39,8 @@ impl RenegadeClient {
         mint: Address,
         amount: Amount,
     ) -> Result<TaskWaiter, RenegadeClientError> {
-        let request = self.build_deposit_request(mint, amount).await?;
-
-        let path = self.build_deposit_request_path(mint, false)?;
-
         let DepositBalanceResponse { task_id, .. } =
-            self.relayer_client.post(&path, request).await?;
+            self.submit_deposit_request(mint, amount, true).await?;
 
         let task_waiter = self.watch_task(task_id, DEFAULT_TASK_TIMEOUT).await?;
 
@@ -56,34 +49,20 @@ impl RenegadeClient {
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
-    /// Builds the request to deposit a balance
-    async fn build_deposit_request(
+impl<S: Signer> RenegadeClient<S> {
+    /// Builds the post-deposit balance & submits the deposit request
+    async fn submit_deposit_request(
         &self,
         mint: Address,
         amount: Amount,
-    ) -> Result<DepositBalanceRequest, RenegadeClientError> {
-        let permit = self.build_deposit_permit(mint, amount).await?;
-
-        let from_address = self.get_account_address();
-        let authority = self.get_schnorr_public_key().into();
-
-        Ok(DepositBalanceRequest { from_address, amount, authority, permit })
-    }
+        non_blocking: bool,
+    ) -> Result<DepositBalanceResponse, RenegadeClientError> {
+        let path = self.build_deposit_request_path(mint, non_blocking)?;
 
-    /// Builds the request to deposit a balance
-    async fn build_deposit_permit(
-        &self,
-        mint: Address,
-        amount: Amount,
-    ) -> Result<ApiDepositPermit, RenegadeClientError> {
         // First, we check if a balance already exists for the token being deposited
-        let existing_balance = self.get_balance_by_mint(mint).await;
-
-        let state_balance = if let Ok(balance) = existing_balance {
+        if let Ok(balance) = self.get_balance_by_mint(mint).await {
             // If a balance already exists for the token being deposited,
             // we update its amount, progressing its cryptographic state accordingly.
-
             let mut state_balance: DarkpoolStateBalance =
                 crate::renegade_wallet_client::conversions::api_balance_to_state_balance(balance)?;
 
@@ -94,11 +73,14 @@ impl RenegadeClient {
 
             state_balance.compute_recovery_id();
 
-            state_balance
-        } else {
-            // If this is a deposit into a new balance, we create the balance state object &
-            // progress its cryptographic state accordingly.
+            let request = self.build_deposit_request(mint, amount, &state_balance).await?;
+            return Ok(self.relayer_client.post(&path, request).await?);
+        }
 
+        // If this is a deposit into a new balance, we create the balance state object
+        // from the next seeds in the account's CSPRNGs & progress its cryptographic
+        // state accordingly.
+        self.with_account_seeds(async |seeds| {
             let balance = DarkpoolBalance::new(
                 mint,
                 self.get_account_address(),
@@ -107,23 +89,43 @@ impl RenegadeClient {
             )
             .with_amount(amount);
 
-            let (mut recovery_seed_csprng, mut share_seed_csprng) =
-                self.get_account_seeds().await?;
-
-            let balance_recovery_stream_seed = recovery_seed_csprng.next().unwrap();
-            let balance_share_stream_seed = share_seed_csprng.next().unwrap();
-
+            let balance_seeds = seeds.next_stream_seeds();
             let mut state_balance = DarkpoolStateBalance::new(
                 balance,
-                balance_share_stream_seed,
-                balance_recovery_stream_seed,
+                balance_seeds.share_stream_seed,
+                balance_seeds.recovery_stream_seed,
             );
 
             state_balance.compute_recovery_id();
 
-            state_balance
-        };
+            let request = self.build_deposit_request(mint, amount, &state_balance).await?;
+            Ok(self.relayer_client.post(&path, request).await?)
+        })
+        .await
+    }
+
+    /// Builds the request to deposit a balance
+    async fn build_deposit_request(
+        &self,
+        mint: Address,
+        amount: Amount,
+        state_balance: &DarkpoolStateBalance,
+    ) -> Result<DepositBalanceRequest, RenegadeClientError> {
+        let permit = self.build_deposit_permit(mint, amount, state_balance).await?;
 
+        let from_address = self.get_account_address();
+        let authority = self.get_schnorr_public_key().into();
+
+        Ok(DepositBalanceRequest { from_address, amount, authority, permit })
+    }
+
+    /// Builds the deposit permit authorizing the post-deposit balance
+    async fn build_deposit_permit(
+        &self,
+        mint: Address,
+        amount: Amount,
+        state_balance: &DarkpoolStateBalance,
+    ) -> Result<ApiDepositPermit, RenegadeClientError> {
         let commitment = scalar_to_u256(&state_balance.compute_commitment());
 
         let deposit = IDarkpoolV2::Deposit {
@@ -132,15 +134,15 @@ impl RenegadeClient {
             amount: u128_to_u256(amount),
         };
 
-        let (witness, signature) = create_deposit_permit(
+        let (witness, signature) = sign_deposit_permit(
             commitment,
-            deposit,
+            &deposit,
             self.get_chain_id(),
             self.get_darkpool_address(),
             self.get_permit2_address(),
             self.get_account_signer(),
         )
-        .map_err(RenegadeClientError::signing)?;
+        .await?;
 
         Ok(ApiDepositPermit {
             nonce: witness.nonce,
@@ -1,20 +1,44 @@
 //! Looks up an account by its ID in the relayer
 
+use alloy::signers::Signer;
 use renegade_external_api::{
     http::account::{GET_ACCOUNT_BY_ID_ROUTE, GetAccountResponse},
     types::ApiAccount,
 };
+use uuid::Uuid;
 
-use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
+use crate::{
+    RenegadeClientError, actions::construct_http_path, client::RenegadeClient,
+    http::RelayerHttpClient, view_client::RenegadeViewClient,
+};
 
-impl RenegadeClient {
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
     /// Look up an account by its ID
     ///
     /// Returns the account's orders and balances
     pub async fn get_account(&self) -> Result<ApiAccount, RenegadeClientError> {
-        let path =
-            construct_http_path!(GET_ACCOUNT_BY_ID_ROUTE, "account_id" => self.get_account_id());
-        let GetAccountResponse { account } = self.relayer_client.get(&path).await?;
-        Ok(account)
+        fetch_account(&self.relayer_client, self.get_account_id()).await
     }
 }
+
+impl RenegadeViewClient {
+    /// Look up an account by its ID
+    ///
+    /// Returns the account's orders and balances
+    pub async fn get_account(&self) -> Result<ApiAccount, RenegadeClientError> {
+        fetch_account(&self.relayer_client, self.get_account_id()).await
+    }
+}
+
+// --- Private Helpers --- //
+
+/// Fetch the account with the given ID from the relayer
+async fn fetch_account(
+    relayer_client: &RelayerHttpClient,
+    account_id: Uuid,
+) -> Result<ApiAccount, RenegadeClientError> {
+    let path = construct_http_path!(GET_ACCOUNT_BY_ID_ROUTE, "account_id" => account_id);
+    let GetAccountResponse { account } = relayer_client.get(&path).await?;
+    Ok(account)
+}
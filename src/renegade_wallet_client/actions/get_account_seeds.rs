@@ -1,18 +1,19 @@
 //! Get an account's seed CSPRNG states from the relayer
 
+use alloy::signers::Signer;
 use renegade_darkpool_types::csprng::PoseidonCSPRNG;
 use renegade_external_api::http::account::{GET_ACCOUNT_SEEDS_ROUTE, GetAccountSeedsResponse};
 
 use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
 
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Get an account's seed CSPRNG states from the relayer.
     /// These are the CSPRNGs used to sample seeds with which to create new
     /// state objects.
     ///
     /// Returns a tuple of (recovery stream seeds CSPRNG, share stream seeds
-    /// CSPRNG)
-    // TODO: Store the CSPRNGs in the client behind a Mutex
+    /// CSPRNG). Actions draw seeds from the client's cached copy of these, see
+    /// [`SeedManager`](crate::seed_manager::SeedManager)
     pub async fn get_account_seeds(
         &self,
     ) -> Result<(PoseidonCSPRNG, PoseidonCSPRNG), RenegadeClientError> {
@@ -1,22 +1,49 @@
 //! Gets the balance of a given mint in the account
 
-use alloy::primitives::Address;
+use alloy::{primitives::Address, signers::Signer};
 use renegade_external_api::{
     http::balance::{GET_BALANCE_BY_MINT_ROUTE, GetBalanceByMintResponse},
     types::ApiBalance,
 };
+use uuid::Uuid;
 
-use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
+use crate::{
+    RenegadeClientError, actions::construct_http_path, client::RenegadeClient,
+    http::RelayerHttpClient, view_client::RenegadeViewClient,
+};
 
-impl RenegadeClient {
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
     /// Get the account's balance for a given mint
     pub async fn get_balance_by_mint(
         &self,
         mint: Address,
     ) -> Result<ApiBalance, RenegadeClientError> {
-        let path = construct_http_path!(GET_BALANCE_BY_MINT_ROUTE, "account_id" => self.get_account_id(), "mint" => mint);
+        fetch_balance_by_mint(&self.relayer_client, self.get_account_id(), mint).await
+    }
+}
 
-        let GetBalanceByMintResponse { balance } = self.relayer_client.get(&path).await?;
-        Ok(balance)
+impl RenegadeViewClient {
+    /// Get the account's balance for a given mint
+    pub async fn get_balance_by_mint(
+        &self,
+        mint: Address,
+    ) -> Result<ApiBalance, RenegadeClientError> {
+        fetch_balance_by_mint(&self.relayer_client, self.get_account_id(), mint).await
     }
 }
+
+// --- Private Helpers --- //
+
+/// Fetch the balance of the given mint in the account from the relayer
+async fn fetch_balance_by_mint(
+    relayer_client: &RelayerHttpClient,
+    account_id: Uuid,
+    mint: Address,
+) -> Result<ApiBalance, RenegadeClientError> {
+    let path =
+        construct_http_path!(GET_BALANCE_BY_MINT_ROUTE, "account_id" => account_id, "mint" => mint);
+
+    let GetBalanceByMintResponse { balance } = relayer_client.get(&path).await?;
+    Ok(balance)
+}
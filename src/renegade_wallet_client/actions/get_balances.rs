@@ -1,19 +1,42 @@
 //! Gets all of the balances in the account
 
+use alloy::signers::Signer;
 use renegade_external_api::{
     http::balance::{GET_BALANCES_ROUTE, GetBalancesResponse},
     types::ApiBalance,
 };
+use uuid::Uuid;
 
-use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
+use crate::{
+    RenegadeClientError, actions::construct_http_path, client::RenegadeClient,
+    http::RelayerHttpClient, view_client::RenegadeViewClient,
+};
 
-impl RenegadeClient {
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
     /// Fetches all balances in the account.
     pub async fn get_balances(&self) -> Result<Vec<ApiBalance>, RenegadeClientError> {
-        let path = construct_http_path!(GET_BALANCES_ROUTE, "account_id" => self.get_account_id());
-
-        let GetBalancesResponse { balances } = self.relayer_client.get(&path).await?;
+        fetch_balances(&self.relayer_client, self.get_account_id()).await
+    }
+}
 
-        Ok(balances)
+impl RenegadeViewClient {
+    /// Fetches all balances in the account.
+    pub async fn get_balances(&self) -> Result<Vec<ApiBalance>, RenegadeClientError> {
+        fetch_balances(&self.relayer_client, self.get_account_id()).await
     }
 }
+
+// --- Private Helpers --- //
+
+/// Fetch all balances in the account with the given ID from the relayer
+async fn fetch_balances(
+    relayer_client: &RelayerHttpClient,
+    account_id: Uuid,
+) -> Result<Vec<ApiBalance>, RenegadeClientError> {
+    let path = construct_http_path!(GET_BALANCES_ROUTE, "account_id" => account_id);
+
+    let GetBalancesResponse { balances } = relayer_client.get(&path).await?;
+
+    Ok(balances)
+}
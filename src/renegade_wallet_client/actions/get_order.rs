@@ -1,15 +1,19 @@
 //! Looks up an order by its ID in the relayer
 
+use alloy::signers::Signer;
 use renegade_external_api::{
     http::order::{GET_ORDER_BY_ID_ROUTE, GetOrderByIdResponse},
     types::{ApiOrder, OrderAuth},
 };
 use uuid::Uuid;
 
-use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
+use crate::{
+    RenegadeClientError, actions::construct_http_path, client::RenegadeClient,
+    http::RelayerHttpClient, view_client::RenegadeViewClient,
+};
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Look up an order by its ID
     pub async fn get_order(&self, order_id: Uuid) -> Result<ApiOrder, RenegadeClientError> {
         let (order, _auth) = self.get_order_with_auth(order_id).await?;
@@ -17,15 +21,33 @@ impl RenegadeClient {
     }
 }
 
+impl RenegadeViewClient {
+    /// Look up an order by its ID
+    pub async fn get_order(&self, order_id: Uuid) -> Result<ApiOrder, RenegadeClientError> {
+        let (order, _auth) =
+            fetch_order_with_auth(&self.relayer_client, self.get_account_id(), order_id).await?;
+        Ok(order)
+    }
+}
+
 // --- Private Helpers --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Gets the order and its auth from the relayer
     pub(crate) async fn get_order_with_auth(
         &self,
         order_id: Uuid,
     ) -> Result<(ApiOrder, OrderAuth), RenegadeClientError> {
-        let path = construct_http_path!(GET_ORDER_BY_ID_ROUTE, "account_id" => self.get_account_id(), "order_id" => order_id);
-        let GetOrderByIdResponse { order, auth } = self.relayer_client.get(&path).await?;
-        Ok((order, auth))
+        fetch_order_with_auth(&self.relayer_client, self.get_account_id(), order_id).await
     }
 }
+
+/// Fetch the order with the given ID and its auth from the relayer
+async fn fetch_order_with_auth(
+    relayer_client: &RelayerHttpClient,
+    account_id: Uuid,
+    order_id: Uuid,
+) -> Result<(ApiOrder, OrderAuth), RenegadeClientError> {
+    let path = construct_http_path!(GET_ORDER_BY_ID_ROUTE, "account_id" => account_id, "order_id" => order_id);
+    let GetOrderByIdResponse { order, auth } = relayer_client.get(&path).await?;
+    Ok((order, auth))
+}
@@ -1,20 +1,42 @@
 //! Looks up a task by its ID in the relayer
 
+use alloy::signers::Signer;
 use renegade_external_api::{
     http::task::{GET_TASK_BY_ID_ROUTE, GetTaskByIdResponse},
     types::ApiTask,
 };
 use uuid::Uuid;
 
-use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
+use crate::{
+    RenegadeClientError, actions::construct_http_path, client::RenegadeClient,
+    http::RelayerHttpClient, view_client::RenegadeViewClient,
+};
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Look up a task by its ID
     pub async fn get_task(&self, task_id: Uuid) -> Result<ApiTask, RenegadeClientError> {
-        let path = construct_http_path!(GET_TASK_BY_ID_ROUTE, "account_id" => self.get_account_id(), "task_id" => task_id);
+        fetch_task(&self.relayer_client, self.get_account_id(), task_id).await
+    }
+}
 
-        let GetTaskByIdResponse { task } = self.relayer_client.get(&path).await?;
-        Ok(task)
+impl RenegadeViewClient {
+    /// Look up a task by its ID
+    pub async fn get_task(&self, task_id: Uuid) -> Result<ApiTask, RenegadeClientError> {
+        fetch_task(&self.relayer_client, self.get_account_id(), task_id).await
     }
 }
+
+// --- Private Helpers --- //
+
+/// Fetch the task with the given ID from the relayer
+async fn fetch_task(
+    relayer_client: &RelayerHttpClient,
+    account_id: Uuid,
+    task_id: Uuid,
+) -> Result<ApiTask, RenegadeClientError> {
+    let path = construct_http_path!(GET_TASK_BY_ID_ROUTE, "account_id" => account_id, "task_id" => task_id);
+
+    let GetTaskByIdResponse { task } = relayer_client.get(&path).await?;
+    Ok(task)
+}
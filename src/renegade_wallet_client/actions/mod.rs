@@ -8,6 +8,7 @@ pub mod admin_get_order;
 pub mod admin_is_task_queue_paused;
 pub mod admin_place_order_in_pool;
 pub mod approvals;
+pub mod batch;
 pub mod cancel_order;
 pub mod create_account;
 pub mod deposit;
@@ -19,7 +20,9 @@ pub mod get_order;
 pub mod get_orders;
 pub mod get_task;
 pub mod get_tasks;
+pub mod native_eth;
 pub mod place_order;
+pub mod prepare_deposit;
 pub mod sync_account;
 pub mod update_order;
 pub mod withdraw;
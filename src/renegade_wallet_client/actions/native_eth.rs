@@ -0,0 +1,118 @@
+//! Deposit & withdraw native ETH by wrapping it into WETH
+//!
+//! The darkpool only holds ERC20 balances, so native ETH is wrapped into the
+//! chain's WETH before it is deposited, & unwrapped after it is withdrawn. The
+//! balance in the account is an ordinary WETH balance.
+
+use alloy::{
+    primitives::{Address, U256},
+    providers::Provider,
+    signers::Signer,
+    sol_types::SolCall,
+};
+use alloy_rpc_types_eth::{TransactionInput, TransactionRequest};
+use renegade_circuit_types::Amount;
+
+use crate::{
+    RenegadeClientError, actions::prepare_deposit::send_and_confirm, client::RenegadeClient,
+    renegade_wallet_client::utils::IWETH, websocket::TaskWaiter,
+};
+
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
+    /// Wrap native ETH into WETH & deposit it into the account's WETH balance.
+    /// Waits for the deposit task to complete before returning.
+    ///
+    /// The provider must sign transactions as the account address
+    pub async fn deposit_native_eth<P: Provider>(
+        &self,
+        provider: &P,
+        amount: Amount,
+    ) -> Result<(), RenegadeClientError> {
+        self.enqueue_native_eth_deposit(provider, amount).await?.await?;
+        Ok(())
+    }
+
+    /// Wrap native ETH into WETH, send any approvals the deposit needs, &
+    /// enqueue a deposit into the account's WETH balance. Returns a
+    /// `TaskWaiter` that can be used to await task completion.
+    ///
+    /// The provider must sign transactions as the account address
+    pub async fn enqueue_native_eth_deposit<P: Provider>(
+        &self,
+        provider: &P,
+        amount: Amount,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let weth = self.weth_address()?;
+        let wrap_tx = self.build_weth_wrap_tx(U256::from(amount))?;
+        send_and_confirm(provider, wrap_tx.from(self.get_account_address())).await?;
+
+        self.enqueue_deposit_with_approvals(provider, weth, amount).await
+    }
+
+    /// Withdraw from the account's WETH balance & unwrap it into native ETH.
+    /// Waits for the withdrawal to complete before unwrapping.
+    ///
+    /// The provider must sign transactions as the account address
+    pub async fn withdraw_to_native_eth<P: Provider>(
+        &self,
+        provider: &P,
+        amount: Amount,
+    ) -> Result<(), RenegadeClientError> {
+        let weth = self.weth_address()?;
+        self.enqueue_withdrawal(weth, amount).await?.await?;
+
+        let unwrap_tx = self.build_weth_unwrap_tx(U256::from(amount))?;
+        send_and_confirm(provider, unwrap_tx.from(self.get_account_address())).await
+    }
+
+    /// Build a transaction wrapping native ETH into WETH
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of ETH to wrap, in wei
+    ///
+    /// # Returns
+    /// A `TransactionRequest` that can be executed by the user with their
+    /// provider
+    pub fn build_weth_wrap_tx(
+        &self,
+        amount: U256,
+    ) -> Result<TransactionRequest, RenegadeClientError> {
+        let calldata = IWETH::depositCall {}.abi_encode();
+        Ok(TransactionRequest::default()
+            .to(self.weth_address()?)
+            .value(amount)
+            .input(TransactionInput::new(calldata.into())))
+    }
+
+    /// Build a transaction unwrapping WETH into native ETH
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of WETH to unwrap
+    ///
+    /// # Returns
+    /// A `TransactionRequest` that can be executed by the user with their
+    /// provider
+    pub fn build_weth_unwrap_tx(
+        &self,
+        amount: U256,
+    ) -> Result<TransactionRequest, RenegadeClientError> {
+        let calldata = IWETH::withdrawCall { wad: amount }.abi_encode();
+        Ok(TransactionRequest::default()
+            .to(self.weth_address()?)
+            .input(TransactionInput::new(calldata.into())))
+    }
+}
+
+// --- Private Helpers --- //
+impl<S: Signer> RenegadeClient<S> {
+    /// Get the configured WETH address, returning an error if none is set
+    fn weth_address(&self) -> Result<Address, RenegadeClientError> {
+        let weth = self.get_weth_address();
+        if weth == Address::ZERO {
+            return Err(RenegadeClientError::config("no WETH address configured"));
+        }
+
+        Ok(weth)
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use alloy::primitives::Address;
+use alloy::{primitives::Address, signers::Signer};
 use renegade_circuit_types::{Amount, fixed_point::FixedPoint};
 use renegade_crypto::fields::scalar_to_u256;
 use renegade_darkpool_types::{
@@ -16,19 +16,26 @@ use renegade_external_api::{
         SignatureWithNonce as ApiSignatureWithNonce,
     },
 };
-use renegade_solidity_abi::v2::IDarkpoolV2::{PublicIntentPermit, SignatureWithNonce};
+use renegade_solidity_abi::v2::IDarkpoolV2::PublicIntentPermit;
+use tracing::warn;
 use uuid::Uuid;
 
 use crate::{
     RenegadeClientError,
     actions::{NON_BLOCKING_PARAM, construct_http_path},
     client::RenegadeClient,
+    expiry::Expiry,
+    pair::{Side, TradingPair},
+    renegade_wallet_client::{
+        seed_manager::AccountSeeds,
+        signing::{sign_public_intent_permit, sign_with_nonce},
+    },
     utils::unwrap_field,
     websocket::{DEFAULT_TASK_TIMEOUT, TaskWaiter},
 };
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Create a new order builder with the client's account address as the
     /// owner
     pub fn new_order_builder(&self) -> OrderBuilder {
@@ -42,11 +49,9 @@ impl RenegadeClient {
     /// As such, this method alone just registers this order as an intent to
     /// trade with the relayer.
     pub async fn place_order(&self, built_order: BuiltOrder) -> Result<(), RenegadeClientError> {
-        let request = self.build_create_order_request(built_order).await?;
-
-        let path = self.build_create_order_request_path(false)?;
-
-        self.relayer_client.post::<_, CreateOrderResponse>(&path, request).await?;
+        // Submit non-blocking so that the account's CSPRNGs are released as soon
+        // as the relayer accepts the order, then wait for the task
+        self.enqueue_order_placement(built_order).await?.await?;
 
         Ok(())
     }
@@ -62,11 +67,8 @@ impl RenegadeClient {
         &self,
         built_order: BuiltOrder,
     ) -> Result<TaskWaiter, RenegadeClientError> {
-        let request = self.build_create_order_request(built_order).await?;
-
-        let path = self.build_create_order_request_path(true)?;
-
-        let CreateOrderResponse { task_id, .. } = self.relayer_client.post(&path, request).await?;
+        let CreateOrderResponse { task_id, .. } =
+            self.submit_create_order_request(built_order, true).await?;
 
         // Create a task waiter for the task
         let task_waiter = self.watch_task(task_id, DEFAULT_TASK_TIMEOUT).await?;
@@ -75,48 +77,100 @@ impl RenegadeClient {
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
-    /// Builds the order creation request from the given built order
-    async fn build_create_order_request(
+impl<S: Signer> RenegadeClient<S> {
+    /// Authorizes the given order & submits the order creation request
+    pub(crate) async fn submit_create_order_request(
         &self,
         built_order: BuiltOrder,
-    ) -> Result<CreateOrderRequest, RenegadeClientError> {
-        let auth = self.build_order_auth(&built_order.order).await?;
+        non_blocking: bool,
+    ) -> Result<CreateOrderResponse, RenegadeClientError> {
+        let path = self.build_create_order_request_path(non_blocking)?;
+        let BuiltOrder { order, precompute_cancellation_proof, expiry } = built_order;
+
+        // Schedule the expiry before placing the order, so that a placed order is
+        // never left without its expiry
+        let scheduler = match expiry {
+            Some(expiry) => {
+                let scheduler = self.get_expiry_scheduler()?;
+                scheduler.schedule(order.id, expiry.deadline()?)?;
+                Some(scheduler)
+            },
+            None => None,
+        };
+
+        let resp = self
+            .with_order_auth(&order, async |auth| {
+                let request = CreateOrderRequest {
+                    order: order.clone(),
+                    auth,
+                    precompute_cancellation_proof,
+                };
+                Ok(self.relayer_client.post(&path, request).await?)
+            })
+            .await;
+
+        if let (Err(_), Some(scheduler)) = (&resp, scheduler)
+            && let Err(e) = scheduler.unschedule(order.id)
+        {
+            warn!("Failed to remove expiry of unplaced order {}: {e}", order.id);
+        }
+
+        resp
+    }
 
-        Ok(CreateOrderRequest {
-            order: built_order.order,
-            auth,
-            precompute_cancellation_proof: built_order.precompute_cancellation_proof,
+    /// Builds the authorization for the given order, then submits it
+    ///
+    /// Private orders draw seeds from the account's CSPRNGs, so the submission
+    /// runs while the CSPRNGs are locked, & is retried if the relayer rejects
+    /// it because the CSPRNGs were stale
+    pub(crate) async fn with_order_auth<T>(
+        &self,
+        order: &ApiOrderCore,
+        mut submit: impl AsyncFnMut(OrderAuth) -> Result<T, RenegadeClientError>,
+    ) -> Result<T, RenegadeClientError> {
+        if matches!(order.order_type, OrderType::PublicOrder) {
+            let auth = self.build_public_order_auth(order).await?;
+            return submit(auth).await;
+        }
+
+        self.with_account_seeds(async |seeds| {
+            let auth = self.build_private_order_auth(order, seeds).await?;
+            submit(auth).await
         })
+        .await
     }
 
-    /// Builds the order authorization for the given order according to its type
-    pub(crate) async fn build_order_auth(
+    /// Builds the authorization for a public order
+    async fn build_public_order_auth(
         &self,
         order: &ApiOrderCore,
     ) -> Result<OrderAuth, RenegadeClientError> {
-        let intent = order.get_intent();
-
         // For public orders, we only need to sign over the circuit intent & executor
         // address
-        if matches!(order.order_type, OrderType::PublicOrder) {
-            let sol_permit =
-                PublicIntentPermit { intent: intent.into(), executor: self.get_executor_address() };
-            let chain_id = self.get_chain_id();
-            let intent_signature = sol_permit
-                .sign(chain_id, self.get_account_signer())
-                .map_err(RenegadeClientError::signing)?
+        let intent = order.get_intent();
+        let sol_permit =
+            PublicIntentPermit { intent: intent.into(), executor: self.get_executor_address() };
+        let chain_id = self.get_chain_id();
+        let intent_signature =
+            sign_public_intent_permit(&sol_permit, chain_id, self.get_account_signer())
+                .await?
                 .into();
-            let permit: ApiPublicIntentPermit = sol_permit.into();
+        let permit: ApiPublicIntentPermit = sol_permit.into();
 
-            return Ok(OrderAuth::PublicOrder { permit, intent_signature });
-        }
+        Ok(OrderAuth::PublicOrder { permit, intent_signature })
+    }
+
+    /// Builds the authorization for a private order according to its type
+    async fn build_private_order_auth(
+        &self,
+        order: &ApiOrderCore,
+        seeds: &mut AccountSeeds,
+    ) -> Result<OrderAuth, RenegadeClientError> {
+        let intent = order.get_intent();
 
         // For private orders, we need to sample the correct recovery & share stream
         // seeds, then compute a commitment to the intent state object.
-        let (mut recovery_seed_csprng, mut share_seed_csprng) = self.get_account_seeds().await?;
-        let intent_recovery_stream_seed = recovery_seed_csprng.next().unwrap();
-        let intent_share_stream_seed = share_seed_csprng.next().unwrap();
+        let intent_seeds = seeds.next_stream_seeds();
 
         match order.order_type {
             OrderType::NativelySettledPrivateOrder => {
@@ -124,22 +178,22 @@ impl RenegadeClient {
                 // The relayer validates using the same ordering.
                 let mut state_intent = DarkpoolStateIntent::new(
                     intent,
-                    intent_share_stream_seed,
-                    intent_recovery_stream_seed,
+                    intent_seeds.share_stream_seed,
+                    intent_seeds.recovery_stream_seed,
                 );
                 state_intent.compute_recovery_id();
                 let commitment = state_intent.compute_commitment();
 
                 // Sign the commitment with ECDSA using a nonce
-                // SignatureWithNonce::sign internally hashes the payload, so pass raw bytes
+                // `sign_with_nonce` internally hashes the payload, so pass raw bytes
                 let commitment_u256 = scalar_to_u256(&commitment);
                 let chain_id = self.get_chain_id();
-                let sig = SignatureWithNonce::sign(
+                let sig = sign_with_nonce(
                     &commitment_u256.to_be_bytes::<32>(),
                     chain_id,
                     self.get_account_signer(),
                 )
-                .map_err(RenegadeClientError::signing)?;
+                .await?;
                 let intent_signature: ApiSignatureWithNonce = sig.into();
                 Ok(OrderAuth::NativelySettledPrivateOrder { intent_signature })
             },
@@ -149,8 +203,8 @@ impl RenegadeClient {
                 // intent commitment (before compute_recovery_id is called).
                 let state_intent = DarkpoolStateIntent::new(
                     intent,
-                    intent_share_stream_seed,
-                    intent_recovery_stream_seed,
+                    intent_seeds.share_stream_seed,
+                    intent_seeds.recovery_stream_seed,
                 );
                 let commitment = state_intent.compute_commitment();
 
@@ -170,13 +224,11 @@ impl RenegadeClient {
                     self.get_schnorr_public_key(),
                 );
 
-                let balance_recovery_stream_seed = recovery_seed_csprng.next().unwrap();
-                let balance_share_stream_seed = share_seed_csprng.next().unwrap();
-
+                let balance_seeds = seeds.next_stream_seeds();
                 let state_output_balance = DarkpoolStateBalance::new(
                     new_output_balance,
-                    balance_share_stream_seed,
-                    balance_recovery_stream_seed,
+                    balance_seeds.share_stream_seed,
+                    balance_seeds.recovery_stream_seed,
                 );
 
                 let balance_commitment = state_output_balance.compute_commitment();
@@ -216,6 +268,8 @@ pub struct BuiltOrder {
     pub order: ApiOrderCore,
     /// Whether to precompute a cancellation proof for the order
     pub precompute_cancellation_proof: bool,
+    /// When to cancel the order, if it is a good-till-time order
+    pub expiry: Option<Expiry>,
 }
 
 /// Builder for order configuration
@@ -245,6 +299,8 @@ pub struct OrderBuilder {
     allow_external_matches: Option<bool>,
     /// Whether to precompute a cancellation proof for the order.
     precompute_cancellation_proof: Option<bool>,
+    /// When to cancel the order, if it is a good-till-time order.
+    expiry: Option<Expiry>,
 }
 
 impl OrderBuilder {
@@ -261,6 +317,7 @@ impl OrderBuilder {
             order_type: None,
             allow_external_matches: None,
             precompute_cancellation_proof: None,
+            expiry: None,
         }
     }
 
@@ -304,6 +361,33 @@ impl OrderBuilder {
         self
     }
 
+    /// Set the order's mints & amounts from a side, size & limit price on a
+    /// trading pair
+    ///
+    /// E.g. buying 2 WETH at at most 3000 USDC per WETH spends at most 6000
+    /// USDC & receives at least 2 WETH. Amounts are rounded so that the order
+    /// never executes at a price worse than the limit.
+    ///
+    /// # Arguments
+    /// * `pair` - The trading pair, with the decimals of both tokens
+    /// * `side` - Whether to buy or sell the base token
+    /// * `base_size` - The amount of the base token to trade, in atoms
+    /// * `limit_price` - The limit price, in quote units per base unit
+    pub fn limit(
+        mut self,
+        pair: TradingPair,
+        side: Side,
+        base_size: Amount,
+        limit_price: f64,
+    ) -> Result<Self, RenegadeClientError> {
+        let params = pair.limit_order_params(side, base_size, limit_price)?;
+        self.input_mint = Some(params.input_mint);
+        self.output_mint = Some(params.output_mint);
+        self.amount_in = Some(params.amount_in);
+        self.min_output_amount = Some(params.min_output_amount);
+        Ok(self)
+    }
+
     /// Set the minimum fill size
     pub fn with_min_fill_size(mut self, min_fill: Amount) -> Self {
         self.min_fill_size = Some(min_fill);
@@ -328,16 +412,22 @@ impl OrderBuilder {
         self
     }
 
+    /// Cancel the order at the given expiry, either a duration after it is
+    /// placed or a point in time
+    ///
+    /// The order is cancelled by the client's expiry scheduler, which must be
+    /// enabled with [`RenegadeClient::enable_order_expiry`] before the order
+    /// is placed
+    pub fn with_expiry(mut self, expiry: impl Into<Expiry>) -> Self {
+        self.expiry = Some(expiry.into());
+        self
+    }
+
     /// Build the order, validating all required fields
     pub fn build(self) -> Result<BuiltOrder, RenegadeClientError> {
         let amount_in = unwrap_field!(self, amount_in);
 
-        let min_output_amount: FixedPoint = self.min_output_amount.unwrap_or_default().into();
-        let min_price = if min_output_amount == FixedPoint::from(0u64) {
-            FixedPoint::from(0u64)
-        } else {
-            min_output_amount.ceil_div_int(amount_in).into()
-        };
+        let min_price = compute_min_price(self.min_output_amount.unwrap_or_default(), amount_in);
 
         let order = ApiOrderCore {
             id: self.id.unwrap_or_else(Uuid::new_v4),
@@ -355,6 +445,21 @@ impl OrderBuilder {
 
         let precompute_cancellation_proof = self.precompute_cancellation_proof.unwrap_or(false);
 
-        Ok(BuiltOrder { order, precompute_cancellation_proof })
+        Ok(BuiltOrder { order, precompute_cancellation_proof, expiry: self.expiry })
+    }
+}
+
+// ----------------------
+// | Non-Member Helpers |
+// ----------------------
+
+/// Computes the minimum price of an order, in output token per input token,
+/// from the minimum output amount it must receive
+pub(crate) fn compute_min_price(min_output_amount: Amount, amount_in: Amount) -> FixedPoint {
+    let min_output_amount: FixedPoint = min_output_amount.into();
+    if min_output_amount == FixedPoint::from(0u64) {
+        FixedPoint::from(0u64)
+    } else {
+        min_output_amount.ceil_div_int(amount_in).into()
     }
 }
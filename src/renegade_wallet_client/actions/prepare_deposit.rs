@@ -0,0 +1,198 @@
+//! Checks the on-chain requirements of a deposit before it is submitted
+//!
+//! A deposit pulls tokens from the account's address through Permit2, so the
+//! address must hold the tokens, must have approved Permit2 to spend them, and
+//! Permit2 must allow the darkpool to transfer them. The relayer only discovers
+//! a missing requirement when its deposit task fails, so these are checked
+//! up front.
+
+use std::time::Duration;
+
+use alloy::{
+    primitives::{Address, U160, U256, aliases::U48},
+    providers::Provider,
+    signers::Signer,
+};
+use alloy_rpc_types_eth::TransactionRequest;
+use renegade_circuit_types::Amount;
+
+use crate::{
+    RenegadeClientError,
+    client::RenegadeClient,
+    renegade_wallet_client::utils::{IAllowanceTransfer, IERC20, unix_now},
+    websocket::TaskWaiter,
+};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The duration for which a Permit2 allowance built by the preflight is valid
+const PERMIT2_ALLOWANCE_DURATION: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+// ---------
+// | Types |
+// ---------
+
+/// The on-chain state a deposit depends on, & the transactions still needed
+/// before it can succeed
+#[derive(Clone, Debug)]
+pub struct DepositPreflight {
+    /// The mint being deposited
+    pub mint: Address,
+    /// The amount being deposited
+    pub amount: Amount,
+    /// The account address's balance of the token
+    pub token_balance: U256,
+    /// The account address's ERC20 allowance to Permit2
+    pub erc20_allowance: U256,
+    /// The Permit2 allowance to the darkpool, zero if it has expired
+    pub permit2_allowance: U160,
+    /// The transaction approving Permit2 to spend the token, if the current
+    /// ERC20 allowance is insufficient
+    pub erc20_approval_tx: Option<TransactionRequest>,
+    /// The transaction allowing the darkpool to transfer the token through
+    /// Permit2, if the current Permit2 allowance is insufficient
+    pub permit2_allowance_tx: Option<TransactionRequest>,
+}
+
+impl DepositPreflight {
+    /// Whether the account address holds enough of the token for the deposit
+    pub fn has_sufficient_balance(&self) -> bool {
+        self.token_balance >= U256::from(self.amount)
+    }
+
+    /// Whether the deposit can be submitted without further transactions
+    pub fn is_ready(&self) -> bool {
+        self.has_sufficient_balance()
+            && self.erc20_approval_tx.is_none()
+            && self.permit2_allowance_tx.is_none()
+    }
+
+    /// The approval transactions still needed, in the order they should be
+    /// sent
+    pub fn required_transactions(&self) -> Vec<TransactionRequest> {
+        self.erc20_approval_tx.iter().chain(self.permit2_allowance_tx.iter()).cloned().collect()
+    }
+}
+
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
+    /// Check the account address's token balance, ERC20 allowance to Permit2,
+    /// and Permit2 allowance to the darkpool for a deposit
+    ///
+    /// Returns the approval transactions that must be sent before the
+    /// deposit can succeed. The token balance cannot be fixed by the client, so
+    /// callers should check [`DepositPreflight::has_sufficient_balance`].
+    pub async fn prepare_deposit<P: Provider>(
+        &self,
+        provider: &P,
+        mint: Address,
+        amount: Amount,
+    ) -> Result<DepositPreflight, RenegadeClientError> {
+        let owner = self.get_account_address();
+        let permit2 = self.get_permit2_address();
+        let darkpool = self.get_darkpool_address();
+
+        let token = IERC20::new(mint, provider);
+        let token_balance =
+            token.balanceOf(owner).call().await.map_err(RenegadeClientError::rpc)?;
+        let erc20_allowance =
+            token.allowance(owner, permit2).call().await.map_err(RenegadeClientError::rpc)?;
+
+        let allowance = IAllowanceTransfer::new(permit2, provider)
+            .allowance(owner, mint, darkpool)
+            .call()
+            .await
+            .map_err(RenegadeClientError::rpc)?;
+        let now = unix_now()?;
+        let permit2_allowance =
+            if allowance.expiration.to::<u64>() > now { allowance.amount } else { U160::ZERO };
+
+        let erc20_approval_tx = (erc20_allowance < U256::from(amount))
+            .then(|| self.build_erc20_approval_tx(mint, U256::from(amount)));
+
+        let permit2_allowance_tx = (permit2_allowance < U160::from(amount)).then(|| {
+            let expiration = U48::from(now + PERMIT2_ALLOWANCE_DURATION.as_secs());
+            self.build_permit2_allowance_tx(mint, U160::from(amount), expiration)
+        });
+
+        Ok(DepositPreflight {
+            mint,
+            amount,
+            token_balance,
+            erc20_allowance,
+            permit2_allowance,
+            erc20_approval_tx,
+            permit2_allowance_tx,
+        })
+    }
+
+    /// Send the approval transactions a deposit still needs, waiting for each
+    /// to be confirmed
+    ///
+    /// The provider must sign transactions as the account address
+    pub async fn send_deposit_approvals<P: Provider>(
+        &self,
+        provider: &P,
+        preflight: &DepositPreflight,
+    ) -> Result<(), RenegadeClientError> {
+        for tx in preflight.required_transactions() {
+            send_and_confirm(provider, tx.from(self.get_account_address())).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Check a deposit's on-chain requirements, send any approvals it still
+    /// needs, then enqueue it
+    ///
+    /// Returns an error before sending any transaction if the account address
+    /// does not hold enough of the token. The provider must sign transactions
+    /// as the account address.
+    pub async fn enqueue_deposit_with_approvals<P: Provider>(
+        &self,
+        provider: &P,
+        mint: Address,
+        amount: Amount,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        let preflight = self.prepare_deposit(provider, mint, amount).await?;
+        if !preflight.has_sufficient_balance() {
+            let msg = format!(
+                "{} holds {} of {mint:#x}, cannot deposit {amount}",
+                self.get_account_address(),
+                preflight.token_balance,
+            );
+            return Err(RenegadeClientError::insufficient_balance(msg));
+        }
+
+        self.send_deposit_approvals(provider, &preflight).await?;
+        self.enqueue_deposit(mint, amount).await
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Send a transaction & wait for it to be confirmed, returning an error if it
+/// reverts
+pub(crate) async fn send_and_confirm<P: Provider>(
+    provider: &P,
+    tx: TransactionRequest,
+) -> Result<(), RenegadeClientError> {
+    let receipt = provider
+        .send_transaction(tx)
+        .await
+        .map_err(RenegadeClientError::rpc)?
+        .get_receipt()
+        .await
+        .map_err(RenegadeClientError::rpc)?;
+
+    if !receipt.status() {
+        let msg = format!("transaction {:#x} reverted", receipt.transaction_hash);
+        return Err(RenegadeClientError::rpc(msg));
+    }
+
+    Ok(())
+}
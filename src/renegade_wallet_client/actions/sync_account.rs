@@ -1,6 +1,6 @@
 //! Sync an account with onchain state
 
-use alloy::primitives::Address;
+use alloy::{primitives::Address, signers::Signer};
 use renegade_external_api::http::account::{
     SYNC_ACCOUNT_ROUTE, SyncAccountRequest, SyncAccountResponse,
 };
@@ -13,7 +13,7 @@ use crate::{
 };
 
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Sync an account with onchain state. Awaits the completion of the sync
     /// task before returning.
     pub async fn sync_account(&self) -> Result<(), RenegadeClientError> {
@@ -63,7 +63,7 @@ impl RenegadeClient {
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Builds the sync account request
     fn build_sync_account_request(
         &self,
@@ -72,7 +72,7 @@ impl RenegadeClient {
         SyncAccountRequest {
             account_id: self.get_account_id(),
             master_view_seed: self.get_master_view_seed(),
-            auth_hmac_key: self.get_auth_hmac_key().into(),
+            auth_hmac_key: self.get_auth_hmac_key().clone().into(),
             schnorr_public_key: self.get_schnorr_public_key(),
             additional_tokens,
         }
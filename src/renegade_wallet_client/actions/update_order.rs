@@ -1,35 +1,68 @@
 //! Updates an order
 
+use alloy::signers::Signer;
 use renegade_circuit_types::Amount;
 use renegade_external_api::{
-    http::order::{UPDATE_ORDER_ROUTE, UpdateOrderRequest, UpdateOrderResponse},
+    http::order::{
+        CreateOrderResponse, UPDATE_ORDER_ROUTE, UpdateOrderRequest, UpdateOrderResponse,
+    },
     types::{ApiOrder, ApiOrderCore},
 };
 use uuid::Uuid;
 
-use crate::{RenegadeClientError, actions::construct_http_path, client::RenegadeClient};
+use crate::{
+    RenegadeClientError,
+    actions::{
+        construct_http_path,
+        place_order::{BuiltOrder, compute_min_price},
+    },
+    client::RenegadeClient,
+    expiry::Expiry,
+    websocket::DEFAULT_TASK_TIMEOUT,
+};
 
-impl RenegadeClient {
+// --- Public Actions --- //
+impl<S: Signer> RenegadeClient<S> {
     /// Updates an order.
     ///
-    /// Currently, the only parameters which can updated are the order's
-    /// `min_fill_size`, and whether to `allow_external_matches`.
+    /// The order's `min_fill_size`, and whether to `allow_external_matches`,
+    /// are amended in place. Changing the order's input amount or minimum
+    /// output amount changes its intent, so the order is cancelled & replaced
+    /// by a newly authorized order with a new ID. The returned order reflects
+    /// the replacement.
+    ///
+    /// Intents cannot be amended in place: the order's authorization is a
+    /// signature over its intent (or, for private orders, over a commitment to
+    /// it), & the update request carries no authorization, so the relayer has
+    /// no way to verify a changed intent.
     pub async fn update_order(
         &self,
         order_update_config: OrderUpdateConfig,
     ) -> Result<ApiOrder, RenegadeClientError> {
-        let request = self.build_update_order_request(order_update_config).await?;
-        let path = construct_http_path!(UPDATE_ORDER_ROUTE, "account_id" => self.get_account_id(), "order_id" => request.order.id);
-        let UpdateOrderResponse { order } = self.relayer_client.post(&path, request).await?;
+        let amends_intent = order_update_config.amends_intent();
+        let precompute_cancellation_proof =
+            order_update_config.precompute_cancellation_proof.unwrap_or(false);
+        let order = self.build_updated_order(order_update_config).await?;
+
+        if amends_intent {
+            return self.cancel_and_replace_order(order, precompute_cancellation_proof).await;
+        }
+
+        let path = construct_http_path!(UPDATE_ORDER_ROUTE, "account_id" => self.get_account_id(), "order_id" => order.id);
+        let UpdateOrderResponse { order } =
+            self.relayer_client.post(&path, UpdateOrderRequest { order }).await?;
 
         Ok(order)
     }
+}
 
-    /// Builds the order update request
-    async fn build_update_order_request(
+// --- Private Helpers --- //
+impl<S: Signer> RenegadeClient<S> {
+    /// Applies the update to the order
+    async fn build_updated_order(
         &self,
         order_update_config: OrderUpdateConfig,
-    ) -> Result<UpdateOrderRequest, RenegadeClientError> {
+    ) -> Result<ApiOrderCore, RenegadeClientError> {
         let mut order = match order_update_config.initial_order {
             Some(initial_order) => initial_order,
             None => self.get_order(order_update_config.order_id).await?.order,
@@ -43,7 +76,56 @@ impl RenegadeClient {
             order.allow_external_matches = allow_external_matches;
         }
 
-        Ok(UpdateOrderRequest { order })
+        // Changing the input amount alone leaves the minimum price unchanged
+        if let Some(amount_in) = order_update_config.amount_in {
+            order.intent.amount_in = amount_in;
+        }
+
+        if let Some(min_output_amount) = order_update_config.min_output_amount {
+            order.intent.min_price = compute_min_price(min_output_amount, order.intent.amount_in);
+        }
+
+        Ok(order)
+    }
+
+    /// Cancels the order, then places the updated order under a new ID
+    ///
+    /// The order is cancelled first so that the original & the replacement are
+    /// never open at once
+    async fn cancel_and_replace_order(
+        &self,
+        mut order: ApiOrderCore,
+        precompute_cancellation_proof: bool,
+    ) -> Result<ApiOrder, RenegadeClientError> {
+        let original_id = order.id;
+        let expiry = self
+            .order_expiries()
+            .into_iter()
+            .find(|e| e.order_id == original_id)
+            .map(|e| Expiry::At(e.deadline));
+        self.cancel_order(original_id).await?;
+
+        // The replacement inherits the original's expiry
+        order.id = Uuid::new_v4();
+        let replacement_id = order.id;
+        let built_order = BuiltOrder { order, precompute_cancellation_proof, expiry };
+        let replace_failed = |e: RenegadeClientError| {
+            RenegadeClientError::invalid_order_update(format!(
+                "order {original_id} was cancelled, but placing its replacement failed: {e}"
+            ))
+        };
+
+        // Submit non-blocking so that the account's CSPRNGs are released as soon
+        // as the relayer accepts the replacement, then wait for the task
+        let CreateOrderResponse { task_id, .. } =
+            self.submit_create_order_request(built_order, true).await.map_err(replace_failed)?;
+        self.watch_task(task_id, DEFAULT_TASK_TIMEOUT)
+            .await
+            .map_err(replace_failed)?
+            .await
+            .map_err(replace_failed)?;
+
+        self.get_order(replacement_id).await
     }
 }
 
@@ -63,6 +145,14 @@ pub struct OrderUpdateConfig {
     min_fill_size: Option<Amount>,
     /// Whether to allow external matches on the order.
     allow_external_matches: Option<bool>,
+    /// The updated input token amount for the order.
+    amount_in: Option<Amount>,
+    /// The updated minimum output token amount for the order, from which its
+    /// minimum price is recomputed.
+    min_output_amount: Option<Amount>,
+    /// Whether the relayer should precompute a cancellation proof for a
+    /// replacement order.
+    precompute_cancellation_proof: Option<bool>,
 }
 
 impl OrderUpdateConfig {
@@ -98,4 +188,39 @@ impl OrderUpdateConfig {
         self.allow_external_matches = Some(allow_external_matches);
         self
     }
+
+    /// Set the updated input token amount for the order
+    ///
+    /// This changes the order's intent, so the order is cancelled & replaced
+    pub fn with_input_amount(mut self, amount_in: Amount) -> Self {
+        self.amount_in = Some(amount_in);
+        self
+    }
+
+    /// Set the updated minimum output token amount for the order
+    ///
+    /// The order's minimum price is recomputed against its (updated) input
+    /// amount. This changes the order's intent, so the order is cancelled &
+    /// replaced
+    pub fn with_min_output_amount(mut self, min_output_amount: Amount) -> Self {
+        self.min_output_amount = Some(min_output_amount);
+        self
+    }
+
+    /// Set whether the relayer should precompute a cancellation proof for the
+    /// replacement, if the update cancels & replaces the order
+    ///
+    /// The relayer does not report whether the original order precomputed
+    /// one, so this must be set again to carry the setting over. Defaults to
+    /// `false`
+    pub fn with_precompute_cancellation_proof(mut self, precompute: bool) -> Self {
+        self.precompute_cancellation_proof = Some(precompute);
+        self
+    }
+
+    /// Whether the update changes the order's intent, requiring it to be
+    /// re-authorized
+    fn amends_intent(&self) -> bool {
+        self.amount_in.is_some() || self.min_output_amount.is_some()
+    }
 }
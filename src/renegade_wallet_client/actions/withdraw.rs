@@ -2,21 +2,20 @@
 
 use std::time::Duration;
 
-use alloy::primitives::Address;
+use alloy::{primitives::Address, signers::Signer};
 use renegade_circuit_types::Amount;
 use renegade_crypto::fields::scalar_to_u256;
 use renegade_darkpool_types::balance::DarkpoolStateBalance;
 use renegade_external_api::http::balance::{
     WITHDRAW_BALANCE_ROUTE, WithdrawBalanceRequest, WithdrawBalanceResponse,
 };
-use renegade_solidity_abi::v2::{
-    IDarkpoolV2::WithdrawalAuth, transfer_auth::withdrawal::create_withdrawal_auth,
-};
+use renegade_solidity_abi::v2::IDarkpoolV2::WithdrawalAuth;
 
 use crate::{
     RenegadeClientError,
     actions::{NON_BLOCKING_PARAM, construct_http_path},
     client::RenegadeClient,
+    renegade_wallet_client::signing::sign_withdrawal_auth,
     websocket::TaskWaiter,
 };
 
@@ -26,8 +25,28 @@ use crate::{
 /// complete first.
 const TASK_WAITER_TIMEOUT: Duration = Duration::from_secs(120);
 
+// ---------
+// | Types |
+// ---------
+
+/// The amount that can be withdrawn from a balance once its outstanding fees
+/// are paid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawalPreview {
+    /// The mint of the balance
+    pub mint: Address,
+    /// The balance's amount before fees are paid
+    pub gross_balance: Amount,
+    /// The relayer fee owed by the balance, paid before the withdrawal
+    pub relayer_fee: Amount,
+    /// The protocol fee owed by the balance, paid before the withdrawal
+    pub protocol_fee: Amount,
+    /// The maximum amount that can be withdrawn
+    pub max_withdrawable: Amount,
+}
+
 // --- Public Actions --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Withdraw funds from an account balance. Waits for the withdrawal task to
     /// complete before returning.
     pub async fn withdraw(&self, mint: Address, amount: Amount) -> Result<(), RenegadeClientError> {
@@ -58,10 +77,34 @@ impl RenegadeClient {
 
         Ok(task_waiter)
     }
+
+    /// Preview the amount that can be withdrawn from a balance, after the
+    /// relayer & protocol fees it owes are paid
+    pub async fn preview_withdrawal(
+        &self,
+        mint: Address,
+    ) -> Result<WithdrawalPreview, RenegadeClientError> {
+        let state_balance = self.get_state_balance(mint).await?;
+        Ok(preview_withdrawal(mint, state_balance))
+    }
+
+    /// Withdraw the full amount that remains in a balance after its fees are
+    /// paid. Waits for the withdrawal task to complete before returning the
+    /// amount withdrawn.
+    pub async fn withdraw_all(&self, mint: Address) -> Result<Amount, RenegadeClientError> {
+        let WithdrawalPreview { max_withdrawable, .. } = self.preview_withdrawal(mint).await?;
+        if max_withdrawable == 0 {
+            let msg = format!("nothing to withdraw from the {mint:#x} balance after fees");
+            return Err(RenegadeClientError::insufficient_balance(msg));
+        }
+
+        self.withdraw(mint, max_withdrawable).await?;
+        Ok(max_withdrawable)
+    }
 }
 
 // --- Private Helpers --- //
-impl RenegadeClient {
+impl<S: Signer> RenegadeClient<S> {
     /// Builds the request to withdraw from a balance
     async fn build_withdrawal_request(
         &self,
@@ -79,9 +122,7 @@ impl RenegadeClient {
         mint: Address,
         amount: Amount,
     ) -> Result<Vec<u8>, RenegadeClientError> {
-        let balance = self.get_balance_by_mint(mint).await?;
-        let mut state_balance: DarkpoolStateBalance =
-            crate::renegade_wallet_client::conversions::api_balance_to_state_balance(balance)?;
+        let mut state_balance = self.get_state_balance(mint).await?;
 
         // First, we simulate fee payments on the balance.
         // This is necessary because the withdrawal API handler will execute fee
@@ -90,6 +131,16 @@ impl RenegadeClient {
         // withdrawal is correctly updated to reflect this.
         simulate_fee_payments(&mut state_balance);
 
+        // The fees may leave less than the requested amount, in which case we
+        // fail before signing anything
+        let max_withdrawable = state_balance.inner.amount;
+        if amount > max_withdrawable {
+            let msg = format!(
+                "cannot withdraw {amount} of {mint:#x}, at most {max_withdrawable} remains after fees"
+            );
+            return Err(RenegadeClientError::insufficient_balance(msg));
+        }
+
         // Next, we update the balance's amount, progressing its cryptographic state
         // accordingly.
         state_balance.inner.amount -= amount;
@@ -104,12 +155,20 @@ impl RenegadeClient {
         let chain_id = self.get_chain_id();
 
         let WithdrawalAuth { signature } =
-            create_withdrawal_auth(commitment, chain_id, self.get_account_signer())
-                .map_err(RenegadeClientError::signing)?;
+            sign_withdrawal_auth(commitment, chain_id, self.get_account_signer()).await?;
 
         Ok(signature.to_vec())
     }
 
+    /// Fetches a balance & converts it into its state object
+    async fn get_state_balance(
+        &self,
+        mint: Address,
+    ) -> Result<DarkpoolStateBalance, RenegadeClientError> {
+        let balance = self.get_balance_by_mint(mint).await?;
+        crate::renegade_wallet_client::conversions::api_balance_to_state_balance(balance)
+    }
+
     /// Builds the request path for the withdrawal balance endpoint
     fn build_withdrawal_request_path(
         &self,
@@ -129,6 +188,18 @@ impl RenegadeClient {
 // | Non-Member Helpers |
 // ----------------------
 
+/// Computes the withdrawal preview of a balance
+fn preview_withdrawal(mint: Address, mut state_balance: DarkpoolStateBalance) -> WithdrawalPreview {
+    let gross_balance = state_balance.inner.amount;
+    let relayer_fee = state_balance.inner.relayer_fee_balance;
+    let protocol_fee = state_balance.inner.protocol_fee_balance;
+
+    simulate_fee_payments(&mut state_balance);
+    let max_withdrawable = state_balance.inner.amount;
+
+    WithdrawalPreview { mint, gross_balance, relayer_fee, protocol_fee, max_withdrawable }
+}
+
 /// Simulates fee payments on the balance
 ///
 /// Only apply fee simulation if the balance has outstanding fees.
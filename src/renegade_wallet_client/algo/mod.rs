@@ -0,0 +1,7 @@
+//! Client-side execution algorithms
+//!
+//! Renegade orders are single intents. The algorithms here work a larger parent
+//! order by placing & cancelling child orders over time, driven from the
+//! client.
+
+pub mod twap;
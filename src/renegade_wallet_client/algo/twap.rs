@@ -0,0 +1,466 @@
+//! A time-weighted average price (TWAP) execution engine
+//!
+//! A TWAP splits a parent order into slices spread evenly over a duration. At
+//! each slice time the engine cancels the previous child order, then places a
+//! child order for an equal share of what remains unfilled, so that fills
+//! missed by earlier slices are spread over the later ones. Fills are tracked
+//! from the account's fills stream.
+//!
+//! The engine persists its progress to a [`TwapStore`] after every step, so
+//! that a TWAP interrupted by a restart can be resumed with
+//! [`TwapEngine::resume`]. Fills that land while the engine is not running are
+//! not observed, so a resumed TWAP may overfill by at most the size of the
+//! child order that was open when it stopped.
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    pin::pin,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use alloy::signers::Signer;
+use futures_util::{Stream, StreamExt};
+use renegade_circuit_types::Amount;
+use renegade_external_api::types::{ApiOrderCore, OrderSide, websocket::FillMessage};
+use serde::{Deserialize, Serialize};
+use tokio::time::{Instant, sleep_until};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    RenegadeClientError,
+    actions::place_order::BuiltOrder,
+    client::RenegadeClient,
+    renegade_wallet_client::{persistence::JsonDir, utils::unix_now},
+};
+
+// --------------
+// | TWAP State |
+// --------------
+
+/// The persisted progress of a TWAP
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TwapState {
+    /// The ID of the TWAP
+    pub id: Uuid,
+    /// The parent order, whose input amount is the TWAP's total size
+    pub parent: ApiOrderCore,
+    /// The Unix timestamp at which the TWAP started, in seconds
+    pub start_time: u64,
+    /// The duration over which the TWAP executes, in seconds
+    pub duration_secs: u64,
+    /// The number of slices the parent order is split into
+    pub num_slices: u32,
+    /// The index of the next slice to place
+    pub next_slice: u32,
+    /// The input amount filled so far, across all child orders
+    pub filled: Amount,
+    /// The IDs of all child orders placed so far
+    pub child_orders: Vec<Uuid>,
+    /// The ID of the child order currently open, if any
+    pub active_child: Option<Uuid>,
+    /// Whether the TWAP has finished
+    pub completed: bool,
+}
+
+impl TwapState {
+    /// The input amount that remains to be filled
+    pub fn remaining(&self) -> Amount {
+        self.parent.intent.amount_in.saturating_sub(self.filled)
+    }
+
+    /// The Unix timestamp at which the given slice is placed, in seconds
+    fn slice_time(&self, slice: u32) -> u64 {
+        self.start_time + self.duration_secs * u64::from(slice) / u64::from(self.num_slices)
+    }
+
+    /// The Unix timestamp at which the TWAP ends, in seconds
+    fn end_time(&self) -> u64 {
+        self.start_time + self.duration_secs
+    }
+
+    /// The input amount of the next slice, an equal share of what remains
+    fn next_slice_amount(&self) -> Amount {
+        let slices_left = Amount::from(self.num_slices - self.next_slice);
+        self.remaining().div_ceil(slices_left)
+    }
+}
+
+// --------------
+// | TWAP Store |
+// --------------
+
+/// Storage for the progress of TWAPs
+pub trait TwapStore: Send + Sync {
+    /// Save a TWAP's progress, replacing any previously saved progress
+    fn save(&self, state: &TwapState) -> Result<(), RenegadeClientError>;
+    /// Load a TWAP's progress, if it has been saved
+    fn load(&self, id: Uuid) -> Result<Option<TwapState>, RenegadeClientError>;
+    /// Load the progress of all saved TWAPs
+    fn list(&self) -> Result<Vec<TwapState>, RenegadeClientError>;
+    /// Delete a TWAP's progress
+    fn remove(&self, id: Uuid) -> Result<(), RenegadeClientError>;
+}
+
+/// A TWAP store held in memory, which does not survive restarts
+#[derive(Default)]
+pub struct MemoryTwapStore {
+    /// The saved TWAPs
+    states: Mutex<HashMap<Uuid, TwapState>>,
+}
+
+impl MemoryTwapStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TwapStore for MemoryTwapStore {
+    fn save(&self, state: &TwapState) -> Result<(), RenegadeClientError> {
+        self.states.lock().unwrap().insert(state.id, state.clone());
+        Ok(())
+    }
+
+    fn load(&self, id: Uuid) -> Result<Option<TwapState>, RenegadeClientError> {
+        Ok(self.states.lock().unwrap().get(&id).cloned())
+    }
+
+    fn list(&self) -> Result<Vec<TwapState>, RenegadeClientError> {
+        Ok(self.states.lock().unwrap().values().cloned().collect())
+    }
+
+    fn remove(&self, id: Uuid) -> Result<(), RenegadeClientError> {
+        self.states.lock().unwrap().remove(&id);
+        Ok(())
+    }
+}
+
+/// A TWAP store holding one JSON file per TWAP in a directory
+#[derive(Clone, Debug)]
+pub struct FileTwapStore {
+    /// The directory holding the TWAPs
+    dir: JsonDir,
+}
+
+impl FileTwapStore {
+    /// Open a store in the given directory, creating it if it does not exist
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, RenegadeClientError> {
+        Ok(Self { dir: JsonDir::open(dir)? })
+    }
+}
+
+impl TwapStore for FileTwapStore {
+    fn save(&self, state: &TwapState) -> Result<(), RenegadeClientError> {
+        self.dir.save(state.id, state)
+    }
+
+    fn load(&self, id: Uuid) -> Result<Option<TwapState>, RenegadeClientError> {
+        self.dir.load(id)
+    }
+
+    fn list(&self) -> Result<Vec<TwapState>, RenegadeClientError> {
+        self.dir.list()
+    }
+
+    fn remove(&self, id: Uuid) -> Result<(), RenegadeClientError> {
+        self.dir.remove(id)
+    }
+}
+
+// ---------------
+// | TWAP Engine |
+// ---------------
+
+/// Executes a TWAP by placing & cancelling child orders on a schedule
+pub struct TwapEngine<S: Signer> {
+    /// The client used to place & cancel child orders
+    client: RenegadeClient<S>,
+    /// The store the TWAP's progress is saved to
+    store: Arc<dyn TwapStore>,
+    /// The TWAP's progress
+    state: TwapState,
+}
+
+impl<S: Signer + Clone> TwapEngine<S> {
+    /// Create a TWAP of the given parent order, starting now
+    ///
+    /// The parent order's input amount is split into `num_slices` child orders
+    /// over `duration`. Each child order inherits the parent's mints, minimum
+    /// price, order type & external match setting.
+    pub fn new(
+        client: &RenegadeClient<S>,
+        store: Arc<dyn TwapStore>,
+        parent: BuiltOrder,
+        duration: Duration,
+        num_slices: u32,
+    ) -> Result<Self, RenegadeClientError> {
+        let parent = parent.order;
+        if num_slices == 0 || duration.is_zero() {
+            let msg = "a TWAP requires a non-zero duration & slice count";
+            return Err(RenegadeClientError::invalid_order(msg));
+        }
+        if parent.intent.amount_in < Amount::from(num_slices) {
+            let msg = format!("cannot split {} into {num_slices} slices", parent.intent.amount_in);
+            return Err(RenegadeClientError::invalid_order(msg));
+        }
+
+        let state = TwapState {
+            id: Uuid::new_v4(),
+            parent,
+            start_time: unix_now()?,
+            duration_secs: duration.as_secs().max(1),
+            num_slices,
+            next_slice: 0,
+            filled: 0,
+            child_orders: Vec::new(),
+            active_child: None,
+            completed: false,
+        };
+        store.save(&state)?;
+
+        Ok(Self { client: client.clone(), store, state })
+    }
+
+    /// Resume a TWAP from its saved progress
+    ///
+    /// Slices whose time passed while the TWAP was not running are placed
+    /// immediately, one after another.
+    pub fn resume(
+        client: &RenegadeClient<S>,
+        store: Arc<dyn TwapStore>,
+        id: Uuid,
+    ) -> Result<Self, RenegadeClientError> {
+        let state = store
+            .load(id)?
+            .ok_or_else(|| RenegadeClientError::persistence(format!("no saved TWAP {id}")))?;
+
+        Ok(Self { client: client.clone(), store, state })
+    }
+
+    /// Get the TWAP's progress
+    pub fn state(&self) -> &TwapState {
+        &self.state
+    }
+
+    /// Run the TWAP to completion, returning its final progress
+    ///
+    /// Dropping the future stops the TWAP, leaving the open child order, if
+    /// any, in place; the TWAP may be resumed later from its saved progress.
+    pub async fn run(mut self) -> Result<TwapState, RenegadeClientError> {
+        // Subscribe before placing any child order, so that no fill is missed
+        let mut fills = pin!(self.client.subscribe_fills().await?);
+
+        while !self.state.completed {
+            let deadline = if self.state.next_slice < self.state.num_slices {
+                self.state.slice_time(self.state.next_slice)
+            } else {
+                self.state.end_time()
+            };
+
+            self.track_fills_until(&mut fills, deadline).await?;
+            self.retire_active_child().await?;
+
+            if self.state.next_slice == self.state.num_slices || self.state.remaining() == 0 {
+                self.state.completed = true;
+                self.store.save(&self.state)?;
+                break;
+            }
+
+            self.place_next_slice().await?;
+        }
+
+        Ok(self.state)
+    }
+}
+
+// --- Private Helpers --- //
+impl<S: Signer + Clone> TwapEngine<S> {
+    /// Record fills of the TWAP's child orders until the given Unix timestamp
+    async fn track_fills_until(
+        &mut self,
+        fills: &mut (impl Stream<Item = FillMessage> + Unpin),
+        deadline: u64,
+    ) -> Result<(), RenegadeClientError> {
+        let wait = Duration::from_secs(deadline.saturating_sub(unix_now()?));
+        let mut timer = pin!(sleep_until(Instant::now() + wait));
+
+        loop {
+            tokio::select! {
+                _ = &mut timer => return Ok(()),
+                fill = fills.next() => match fill {
+                    Some(fill) => self.record_fill(&fill)?,
+                    None => return Err(RenegadeClientError::subscription("fills stream closed")),
+                },
+            }
+        }
+    }
+
+    /// Record a fill, if it is of one of the TWAP's child orders
+    fn record_fill(&mut self, fill: &FillMessage) -> Result<(), RenegadeClientError> {
+        if !self.state.child_orders.contains(&fill.order_id) {
+            return Ok(());
+        }
+
+        // A fill reports both legs of the trade; the input leg is the quote token
+        // for a buy & the base token for a sell
+        let (input_mint, input_amount) = match fill.side {
+            OrderSide::Buy => (fill.quote_mint, fill.quote_amount),
+            OrderSide::Sell => (fill.base_mint, fill.base_amount),
+        };
+        let in_token = self.state.parent.intent.in_token;
+        if input_mint != in_token {
+            warn!(
+                "Ignoring fill of TWAP {} child order {}: input mint {input_mint} is not {in_token}",
+                self.state.id, fill.order_id
+            );
+            return Ok(());
+        }
+
+        let total = self.state.parent.intent.amount_in;
+        self.state.filled = self.state.filled.saturating_add(input_amount).min(total);
+        self.store.save(&self.state)
+    }
+
+    /// Cancel the open child order, if any
+    ///
+    /// The order may have filled completely, or never been placed if the
+    /// engine stopped mid-placement, in which case the cancellation fails & is
+    /// ignored
+    async fn retire_active_child(&mut self) -> Result<(), RenegadeClientError> {
+        let Some(order_id) = self.state.active_child.take() else {
+            return Ok(());
+        };
+
+        let cancelled = match self.client.enqueue_order_cancellation(order_id).await {
+            Ok(waiter) => waiter.await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = cancelled {
+            warn!("Failed to cancel TWAP {} child order {order_id}: {e}", self.state.id);
+        }
+
+        self.store.save(&self.state)
+    }
+
+    /// Place the next slice's child order
+    async fn place_next_slice(&mut self) -> Result<(), RenegadeClientError> {
+        let amount = self.state.next_slice_amount();
+        let mut order = self.state.parent.clone();
+        order.id = Uuid::new_v4();
+        order.intent.amount_in = amount;
+        order.min_fill_size = order.min_fill_size.min(amount);
+
+        // Record the child as active before placing it, so that its fills are
+        // attributed to the TWAP, & it is cancelled on resume, even if the engine
+        // stops before the placement completes
+        self.state.child_orders.push(order.id);
+        self.state.active_child = Some(order.id);
+        self.store.save(&self.state)?;
+
+        let built_order = BuiltOrder { order, precompute_cancellation_proof: false, expiry: None };
+        self.client.enqueue_order_placement(built_order).await?.await?;
+
+        self.state.next_slice += 1;
+        self.store.save(&self.state)
+    }
+}
+
+// ----------------------
+// | Client Integration |
+// ----------------------
+
+impl<S: Signer + Clone> RenegadeClient<S> {
+    /// Create a TWAP of the given parent order, starting now
+    ///
+    /// See [`TwapEngine::new`]
+    pub fn twap(
+        &self,
+        store: Arc<dyn TwapStore>,
+        parent: BuiltOrder,
+        duration: Duration,
+        num_slices: u32,
+    ) -> Result<TwapEngine<S>, RenegadeClientError> {
+        TwapEngine::new(self, store, parent, duration, num_slices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::Address;
+    use renegade_external_api::types::{ApiIntent, OrderType};
+
+    use crate::actions::place_order::compute_min_price;
+
+    use super::*;
+
+    /// A TWAP of the given size, starting at a fixed time
+    fn twap_state(amount_in: Amount, duration_secs: u64, num_slices: u32) -> TwapState {
+        let parent = ApiOrderCore {
+            id: Uuid::new_v4(),
+            intent: ApiIntent {
+                in_token: Address::repeat_byte(0x01),
+                out_token: Address::repeat_byte(0x02),
+                owner: Address::repeat_byte(0x03),
+                amount_in,
+                min_price: compute_min_price(0, amount_in),
+            },
+            min_fill_size: 0,
+            order_type: OrderType::PublicOrder,
+            allow_external_matches: true,
+        };
+
+        TwapState {
+            id: Uuid::new_v4(),
+            parent,
+            start_time: 1_000,
+            duration_secs,
+            num_slices,
+            next_slice: 0,
+            filled: 0,
+            child_orders: Vec::new(),
+            active_child: None,
+            completed: false,
+        }
+    }
+
+    #[test]
+    fn test_slice_times_span_duration() {
+        let state = twap_state(100, 90, 4);
+
+        let times: Vec<u64> = (0..=4).map(|slice| state.slice_time(slice)).collect();
+        assert_eq!(times, [1_000, 1_022, 1_045, 1_067, 1_090]);
+        assert_eq!(state.slice_time(state.num_slices), state.end_time());
+    }
+
+    #[test]
+    fn test_slice_amounts_split_evenly() {
+        let mut state = twap_state(10, 60, 3);
+        assert_eq!(state.next_slice_amount(), 4);
+
+        state.next_slice = 1;
+        state.filled = 4;
+        assert_eq!(state.next_slice_amount(), 3);
+
+        state.next_slice = 2;
+        state.filled = 7;
+        assert_eq!(state.next_slice_amount(), 3);
+    }
+
+    #[test]
+    fn test_slice_amounts_spread_missed_fills() {
+        let mut state = twap_state(10, 60, 3);
+
+        // The first slice filled only partially, so the shortfall is spread over
+        // the remaining slices
+        state.next_slice = 1;
+        state.filled = 1;
+        assert_eq!(state.next_slice_amount(), 5);
+
+        state.next_slice = 2;
+        state.filled = 10;
+        assert_eq!(state.next_slice_amount(), 0);
+    }
+}
@@ -1,32 +1,39 @@
 //! The client for interacting with the Renegade darkpool API
 
+use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::auth::HmacKey;
 use alloy::primitives::Address;
-use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::{Signer, local::PrivateKeySigner};
 use futures_util::Stream;
 use renegade_circuit_types::schnorr::{SchnorrPrivateKey, SchnorrPublicKey, SchnorrSignature};
 use renegade_circuit_types::traits::BaseType;
 use renegade_constants::Scalar;
 use uuid::Uuid;
+use zeroize::Zeroize;
 
 use renegade_external_api::types::websocket::{
     AdminBalanceUpdateMessage, AdminOrderUpdateMessage, BalanceUpdateMessage, FillMessage,
     OrderUpdateMessage, TaskUpdateMessage,
 };
 
-use crate::util::get_env_agnostic_chain;
 use crate::websocket::TaskWaiter;
 use crate::{
-    ETHEREUM_SEPOLIA_CHAIN_ID, BASE_MAINNET_CHAIN_ID, BASE_SEPOLIA_CHAIN_ID, RenegadeClientError,
+    RenegadeClientError,
     http::RelayerHttpClient,
+    network::NetworkConfig,
     renegade_wallet_client::{
+        account_state::AccountState,
         config::RenegadeClientConfig,
+        expiry::ExpirySlot,
+        seed_manager::SeedManager,
         utils::{
-            derive_account_id, derive_auth_hmac_key, derive_master_view_seed, derive_schnorr_key,
+            DEFAULT_ACCOUNT_INDEX, derive_account_id, derive_auth_hmac_key,
+            derive_master_view_seed, derive_schnorr_key,
         },
+        view_client::{RenegadeViewClient, ViewKeys},
     },
     websocket::RenegadeWebsocketClient,
 };
@@ -36,7 +43,12 @@ use crate::{
 // -----------
 
 /// The secrets used to authenticate account actions
-#[derive(Copy, Clone)]
+///
+/// The secret values are redacted from `Debug` output, and the Schnorr & HMAC
+/// keys are zeroized on drop. The master view seed's field type does not
+/// expose its limbs, so it is not. The type is deliberately not `Copy`, so
+/// that copies of the secrets are explicit.
+#[derive(Clone)]
 pub struct AccountSecrets {
     /// The ID of the account
     pub account_id: Uuid,
@@ -51,30 +63,74 @@ pub struct AccountSecrets {
 
 impl AccountSecrets {
     /// Generate a new set of account secrets from a signing key & chain ID
-    pub fn new(key: &PrivateKeySigner, chain_id: u64) -> Result<Self, RenegadeClientError> {
-        let account_id = derive_account_id(key, chain_id).map_err(RenegadeClientError::setup)?;
+    pub async fn new<S: Signer + ?Sized>(
+        key: &S,
+        chain_id: u64,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::new_with_account_index(key, chain_id, DEFAULT_ACCOUNT_INDEX).await
+    }
 
-        let master_view_seed =
-            derive_master_view_seed(key, chain_id).map_err(RenegadeClientError::setup)?;
+    /// Generate the secrets of the account at the given index from a signing
+    /// key & chain ID
+    ///
+    /// Each index derives an independent account under the same key. Index
+    /// zero is the account derived by [`AccountSecrets::new`].
+    pub async fn new_with_account_index<S: Signer + ?Sized>(
+        key: &S,
+        chain_id: u64,
+        account_index: u32,
+    ) -> Result<Self, RenegadeClientError> {
+        let account_id = derive_account_id(key, chain_id, account_index)
+            .await
+            .map_err(RenegadeClientError::setup)?;
 
-        let schnorr_key = derive_schnorr_key(key, chain_id).map_err(RenegadeClientError::setup)?;
+        let master_view_seed = derive_master_view_seed(key, chain_id, account_index)
+            .await
+            .map_err(RenegadeClientError::setup)?;
 
-        let auth_hmac_key =
-            derive_auth_hmac_key(key, chain_id).map_err(RenegadeClientError::setup)?;
+        let schnorr_key = derive_schnorr_key(key, chain_id, account_index)
+            .await
+            .map_err(RenegadeClientError::setup)?;
+
+        let auth_hmac_key = derive_auth_hmac_key(key, chain_id, account_index)
+            .await
+            .map_err(RenegadeClientError::setup)?;
 
         Ok(Self { account_id, master_view_seed, schnorr_key, auth_hmac_key })
     }
 }
 
+impl fmt::Debug for AccountSecrets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccountSecrets")
+            .field("account_id", &self.account_id)
+            .field("master_view_seed", &"[REDACTED]")
+            .field("schnorr_key", &"[REDACTED]")
+            .field("auth_hmac_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl Drop for AccountSecrets {
+    fn drop(&mut self) {
+        // The HMAC key zeroizes itself on drop
+        self.schnorr_key.inner.0.0.zeroize();
+    }
+}
+
 // -------------------
 // | Darkpool Client |
 // -------------------
 
 /// The Renegade wallet client
+///
+/// Generic over the signer holding the account key, which defaults to a local
+/// private key. The signer derives the account secrets and signs deposit,
+/// withdrawal, order, and cancellation authorizations.
 #[derive(Clone)]
-pub struct RenegadeClient {
+pub struct RenegadeClient<S = PrivateKeySigner> {
     /// The client config
-    pub config: RenegadeClientConfig,
+    pub config: RenegadeClientConfig<S>,
     /// The account secrets
     pub secrets: AccountSecrets,
     /// The relayer HTTP client
@@ -88,114 +144,164 @@ pub struct RenegadeClient {
     pub historical_state_client: Arc<RelayerHttpClient>,
     /// The websocket client
     pub websocket_client: RenegadeWebsocketClient,
+    /// The local copy of the account's seed CSPRNGs, shared between clones of
+    /// the client
+    pub seed_manager: Arc<SeedManager>,
+    /// The scheduler cancelling orders at their expiry, once enabled, shared
+    /// between clones of the client
+    pub(crate) expiry_scheduler: Arc<ExpirySlot>,
 }
 
-impl RenegadeClient {
-    /// Derive the wallet secrets from an ethereum private key
-    pub fn new(config: RenegadeClientConfig) -> Result<Self, RenegadeClientError> {
-        let secrets = AccountSecrets::new(&config.key, config.chain_id)?;
+impl<S: Signer + Clone> RenegadeClient<S> {
+    /// Derive the wallet secrets from the configured signer
+    pub async fn new(config: RenegadeClientConfig<S>) -> Result<Self, RenegadeClientError> {
+        let secrets = AccountSecrets::new_with_account_index(
+            &config.key,
+            config.chain_id,
+            config.account_index,
+        )
+        .await?;
+        Ok(Self::from_secrets(config, secrets))
+    }
+
+    /// Create a client for the account at the given index under the same
+    /// signer
+    ///
+    /// Each index is an independent account with its own balances & orders,
+    /// e.g. to isolate strategies from one another. The account must be
+    /// created with the relayer before it is used.
+    pub async fn with_account_index(
+        &self,
+        account_index: u32,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::new(self.config.clone().with_account_index(account_index)).await
+    }
 
+    /// Create a client from previously derived account secrets, e.g. those
+    /// loaded from a keystore
+    ///
+    /// No secrets are derived, so the configured signer is only used to sign
+    /// deposit, withdrawal, order, and cancellation authorizations. It may be
+    /// a remote signer whose key never enters the process.
+    pub fn from_secrets(config: RenegadeClientConfig<S>, secrets: AccountSecrets) -> Self {
         let relayer_client =
-            RelayerHttpClient::new(config.relayer_base_url.clone(), secrets.auth_hmac_key);
+            RelayerHttpClient::new(config.relayer_base_url.clone(), secrets.auth_hmac_key.clone());
 
         let admin_relayer_client = config
             .admin_hmac_key
+            .clone()
             .map(|key| RelayerHttpClient::new(config.relayer_base_url.clone(), key));
 
-        let chain = get_env_agnostic_chain(config.chain_id);
         let historical_state_client = Arc::new(RelayerHttpClient::new(
-            format!("{}/{chain}", config.historical_state_base_url),
-            secrets.auth_hmac_key,
+            format!("{}/{}", config.historical_state_base_url, config.chain_name),
+            secrets.auth_hmac_key.clone(),
         ));
 
         let websocket_client = RenegadeWebsocketClient::new(
             &config,
             secrets.account_id,
-            secrets.auth_hmac_key,
-            config.admin_hmac_key,
+            secrets.auth_hmac_key.clone(),
+            config.admin_hmac_key.clone(),
         );
 
-        Ok(Self {
+        Self {
             config,
             secrets,
             relayer_client,
             admin_relayer_client,
             historical_state_client,
             websocket_client,
-        })
+            seed_manager: Arc::new(SeedManager::new()),
+            expiry_scheduler: Arc::default(),
+        }
+    }
+
+    /// Create a new wallet on the given network
+    pub async fn from_network(
+        network: impl Into<NetworkConfig>,
+        key: &S,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::from_network(network, key)).await
+    }
+
+    /// Create a new admin wallet on the given network
+    pub async fn from_network_admin(
+        network: impl Into<NetworkConfig>,
+        key: &S,
+        admin_hmac_key: HmacKey,
+    ) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::from_network_admin(network, key, admin_hmac_key)).await
     }
 
     /// Create a new wallet on Arbitrum Sepolia
-    pub fn new_arbitrum_sepolia(key: &PrivateKeySigner) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_arbitrum_sepolia(key))
+    pub async fn new_arbitrum_sepolia(key: &S) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::new_arbitrum_sepolia(key)).await
     }
 
     /// Create a new admin wallet on Arbitrum Sepolia
-    pub fn new_arbitrum_sepolia_admin(
-        key: &PrivateKeySigner,
+    pub async fn new_arbitrum_sepolia_admin(
+        key: &S,
         admin_hmac_key: HmacKey,
     ) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_arbitrum_sepolia_admin(key, admin_hmac_key))
+        Self::new(RenegadeClientConfig::new_arbitrum_sepolia_admin(key, admin_hmac_key)).await
     }
 
     /// Create a new wallet on Arbitrum One
-    pub fn new_arbitrum_one(key: &PrivateKeySigner) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_arbitrum_one(key))
+    pub async fn new_arbitrum_one(key: &S) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::new_arbitrum_one(key)).await
     }
 
     /// Create a new admin wallet on Arbitrum One
-    pub fn new_arbitrum_one_admin(
-        key: &PrivateKeySigner,
+    pub async fn new_arbitrum_one_admin(
+        key: &S,
         admin_hmac_key: HmacKey,
     ) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_arbitrum_one_admin(key, admin_hmac_key))
+        Self::new(RenegadeClientConfig::new_arbitrum_one_admin(key, admin_hmac_key)).await
     }
 
     /// Create a new wallet on Base Sepolia
-    pub fn new_base_sepolia(key: &PrivateKeySigner) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_base_sepolia(key))
+    pub async fn new_base_sepolia(key: &S) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::new_base_sepolia(key)).await
     }
 
     /// Create a new admin wallet on Base Sepolia
-    pub fn new_base_sepolia_admin(
-        key: &PrivateKeySigner,
+    pub async fn new_base_sepolia_admin(
+        key: &S,
         admin_hmac_key: HmacKey,
     ) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_base_sepolia_admin(key, admin_hmac_key))
+        Self::new(RenegadeClientConfig::new_base_sepolia_admin(key, admin_hmac_key)).await
     }
 
     /// Create a new wallet on Base Mainnet
-    pub fn new_base_mainnet(key: &PrivateKeySigner) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_base_mainnet(key))
+    pub async fn new_base_mainnet(key: &S) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::new_base_mainnet(key)).await
     }
 
     /// Create a new admin wallet on Ethereum Sepolia
-    pub fn new_ethereum_sepolia_admin(
-        key: &PrivateKeySigner,
+    pub async fn new_ethereum_sepolia_admin(
+        key: &S,
         admin_hmac_key: HmacKey,
     ) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_ethereum_sepolia_admin(key, admin_hmac_key))
+        Self::new(RenegadeClientConfig::new_ethereum_sepolia_admin(key, admin_hmac_key)).await
     }
 
     /// Create a new admin wallet on Ethereum Sepolia
-    pub fn new_ethereum_sepolia(key: &PrivateKeySigner) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_ethereum_sepolia(key))
+    pub async fn new_ethereum_sepolia(key: &S) -> Result<Self, RenegadeClientError> {
+        Self::new(RenegadeClientConfig::new_ethereum_sepolia(key)).await
     }
 
     /// Create a new admin wallet on Base Mainnet
-    pub fn new_base_mainnet_admin(
-        key: &PrivateKeySigner,
+    pub async fn new_base_mainnet_admin(
+        key: &S,
         admin_hmac_key: HmacKey,
     ) -> Result<Self, RenegadeClientError> {
-        Self::new(RenegadeClientConfig::new_base_mainnet_admin(key, admin_hmac_key))
+        Self::new(RenegadeClientConfig::new_base_mainnet_admin(key, admin_hmac_key)).await
     }
 
     /// Whether the client is on a chain in which Renegade is deployed as a
     /// solidity contract
     pub fn is_solidity_chain(&self) -> bool {
-        self.config.chain_id == BASE_MAINNET_CHAIN_ID
-            || self.config.chain_id == BASE_SEPOLIA_CHAIN_ID
-            || self.config.chain_id == ETHEREUM_SEPOLIA_CHAIN_ID
+        self.config.is_solidity_chain
     }
 
     // --------------
@@ -212,45 +318,51 @@ impl RenegadeClient {
         self.websocket_client.watch_task(task_id, timeout).await
     }
 
+    /// Load an in-memory mirror of the account's state, kept up to date by
+    /// websocket updates
+    pub async fn account_state(&self) -> Result<AccountState, RenegadeClientError> {
+        AccountState::new(self.view_client()).await
+    }
+
     /// Subscribe to the account's task updates stream
     pub async fn subscribe_task_updates(
         &self,
-    ) -> Result<impl Stream<Item = TaskUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = TaskUpdateMessage> + use<>, RenegadeClientError> {
         self.websocket_client.subscribe_task_updates().await
     }
 
     /// Subscribe to the account's balance updates stream
     pub async fn subscribe_balance_updates(
         &self,
-    ) -> Result<impl Stream<Item = BalanceUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = BalanceUpdateMessage> + use<>, RenegadeClientError> {
         self.websocket_client.subscribe_balance_updates().await
     }
 
     /// Subscribe to the account's order updates stream
     pub async fn subscribe_order_updates(
         &self,
-    ) -> Result<impl Stream<Item = OrderUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = OrderUpdateMessage> + use<>, RenegadeClientError> {
         self.websocket_client.subscribe_order_updates().await
     }
 
     /// Subscribe to the account's fills stream
     pub async fn subscribe_fills(
         &self,
-    ) -> Result<impl Stream<Item = FillMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = FillMessage> + use<>, RenegadeClientError> {
         self.websocket_client.subscribe_fills().await
     }
 
     /// Subscribe to the admin balances updates stream
     pub async fn subscribe_admin_balance_updates(
         &self,
-    ) -> Result<impl Stream<Item = AdminBalanceUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = AdminBalanceUpdateMessage> + use<>, RenegadeClientError> {
         self.websocket_client.subscribe_admin_balance_updates().await
     }
 
     /// Subscribe to the admin order updates stream
     pub async fn subscribe_admin_order_updates(
         &self,
-    ) -> Result<impl Stream<Item = AdminOrderUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = AdminOrderUpdateMessage> + use<>, RenegadeClientError> {
         self.websocket_client.subscribe_admin_order_updates().await
     }
 
@@ -278,17 +390,33 @@ impl RenegadeClient {
     }
 
     /// Get the HMAC key used to authenticate account API actions
-    pub fn get_auth_hmac_key(&self) -> HmacKey {
-        self.secrets.auth_hmac_key
+    pub fn get_auth_hmac_key(&self) -> &HmacKey {
+        &self.secrets.auth_hmac_key
+    }
+
+    /// Get the keys needed to read the account's state, which may be shared
+    /// with a [`RenegadeViewClient`] without granting access to funds
+    pub fn view_keys(&self) -> ViewKeys {
+        ViewKeys::from(&self.secrets)
     }
 
-    /// Get the signing key client is configured with
-    pub fn get_account_signer(&self) -> &PrivateKeySigner {
+    /// Create a read-only client for the account, sharing this client's
+    /// relayer & websocket connections
+    pub fn view_client(&self) -> RenegadeViewClient {
+        RenegadeViewClient::from_parts(
+            self.view_keys(),
+            self.relayer_client.clone(),
+            self.websocket_client.clone(),
+        )
+    }
+
+    /// Get the signer the client is configured with
+    pub fn get_account_signer(&self) -> &S {
         &self.config.key
     }
 
-    /// Get the address of the account associated with the private key the
-    /// client is configured with
+    /// Get the address of the account associated with the signer the client
+    /// is configured with
     pub fn get_account_address(&self) -> Address {
         self.config.key.address()
     }
@@ -332,4 +460,9 @@ impl RenegadeClient {
     pub fn get_darkpool_address(&self) -> Address {
         self.config.darkpool_address
     }
+
+    /// Get the WETH address the client is configured for
+    pub fn get_weth_address(&self) -> Address {
+        self.config.weth_address
+    }
 }
@@ -1,112 +1,39 @@
 //! Config setup for the renegade wallet client
 
-// -------------
-// | Constants |
-// -------------
+use std::{str::FromStr, time::Duration};
 
 use crate::auth::HmacKey;
-use alloy::{
-    primitives::{Address, address},
-    signers::local::PrivateKeySigner,
-};
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
 
+#[cfg(feature = "settings")]
+use crate::settings::{ClientSettings, ConfigError};
 use crate::{
-    ARBITRUM_ONE_CHAIN_ID, ARBITRUM_ONE_RELAYER_BASE_URL, ARBITRUM_SEPOLIA_CHAIN_ID,
-    ARBITRUM_SEPOLIA_RELAYER_BASE_URL, BASE_MAINNET_CHAIN_ID, BASE_MAINNET_RELAYER_BASE_URL,
-    BASE_SEPOLIA_CHAIN_ID, BASE_SEPOLIA_RELAYER_BASE_URL, ETHEREUM_SEPOLIA_CHAIN_ID,
-    ETHEREUM_SEPOLIA_RELAYER_BASE_URL,
+    RenegadeClientError,
+    http::RelayerHttpClient,
+    network::{CANONICAL_PERMIT2_ADDRESS, Network, NetworkConfig},
+    renegade_wallet_client::utils::DEFAULT_ACCOUNT_INDEX,
+    types::exchange_metadata::{ExchangeMetadataResponse, GET_EXCHANGE_METADATA_ROUTE},
 };
 
-// --- Historical State URLs --- //
-
-/// The mainnet historical state base URL
-pub(crate) const MAINNET_HISTORICAL_STATE_BASE_URL: &str =
-    "https://mainnet.historical-state.renegade.fi";
-/// The testnet historical state base URL
-pub(crate) const TESTNET_HISTORICAL_STATE_BASE_URL: &str =
-    "https://testnet.historical-state.renegade.fi";
-
-// --- Darkpool Addresses --- //
-
-/// The darkpool address on Arbitrum One
-pub(crate) const ARBITRUM_ONE_DARKPOOL_ADDRESS: Address =
-    address!("0xC5D1B8096BbdEC83Bc6049e42822c7483BBA6500");
-/// The darkpool address on Arbitrum Sepolia
-pub(crate) const ARBITRUM_SEPOLIA_DARKPOOL_ADDRESS: Address =
-    address!("0x57dF3a4449aaBf72f61e4A5DFe83d4A45DcC8537");
-/// The darkpool address on Base Mainnet
-pub(crate) const BASE_MAINNET_DARKPOOL_ADDRESS: Address =
-    address!("0x15d7CF277BE6463F153Dd0d4d73F92Ad65e6348C");
-/// The darkpool address on Base Sepolia
-pub(crate) const BASE_SEPOLIA_DARKPOOL_ADDRESS: Address =
-    address!("0xDE9BfD62B2187d4c14FBcC7D869920d34e4DB3Da");
-/// The darkpool address on Ethereum Sepolia
-pub(crate) const ETHEREUM_SEPOLIA_DARKPOOL_ADDRESS: Address =
-    address!("0x45537c28F245645CC1E7F7258FCC18A189CE16e3");
-
-// --- Permit2 Addresses --- //
-
-/// The permit2 address on Arbitrum One
-pub(crate) const ARBITRUM_ONE_PERMIT2_ADDRESS: Address =
-    address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
-/// The permit2 address on Arbitrum Sepolia
-pub(crate) const ARBITRUM_SEPOLIA_PERMIT2_ADDRESS: Address =
-    address!("0x9458198bcc289c42e460cb8ca143e5854f734442");
-/// The permit2 address on Base Mainnet
-pub(crate) const BASE_MAINNET_PERMIT2_ADDRESS: Address =
-    address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
-/// The permit2 address on Base Sepolia
-pub(crate) const BASE_SEPOLIA_PERMIT2_ADDRESS: Address =
-    address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
-/// The permit2 address on Ethereum Sepolia
-pub(crate) const ETHEREUM_SEPOLIA_PERMIT2_ADDRESS: Address =
-    address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
-
-// --- Executor Addresses --- //
-
-/// The executor address on Arbitrum One
-pub(crate) const ARBITRUM_ONE_EXECUTOR_ADDRESS: Address =
-    address!("0x336A6b8AE5589d40ba4391020649E268E8323CA1");
-/// The executor address on Arbitrum Sepolia
-pub(crate) const ARBITRUM_SEPOLIA_EXECUTOR_ADDRESS: Address =
-    address!("0x9094314D60e3eF5fC73df548A3dD7b1Cd9798729");
-/// The executor address on Base Mainnet
-pub(crate) const BASE_MAINNET_EXECUTOR_ADDRESS: Address =
-    address!("0x1b5A1833d8566FACb138aa6BF1cd040f572B1D56");
-/// The executor address on Base Sepolia
-pub(crate) const BASE_SEPOLIA_EXECUTOR_ADDRESS: Address =
-    address!("0x5E2ca57B7F09Cf3DAca07c67CC65e1BfbDf346b0");
-/// The executor address on Ethereum Sepolia
-pub(crate) const ETHEREUM_SEPOLIA_EXECUTOR_ADDRESS: Address =
-    address!("0x92467D2FF278383187f0aB04F8511EF45c31b723");
-
-// --- Relayer Fee Recipient Addresses --- //
-
-/// The relayer fee recipient address on Arbitrum One
-pub(crate) const ARBITRUM_ONE_RELAYER_FEE_RECIPIENT: Address =
-    address!("0x0000000000000000000000000000000000000000");
-/// The relayer fee recipient address on Arbitrum Sepolia
-pub(crate) const ARBITRUM_SEPOLIA_RELAYER_FEE_RECIPIENT: Address =
-    address!("0xb0c0d3e8ebc39df5799d9c98d65dacf8637deba1");
-/// The relayer fee recipient address on Base Mainnet
-pub(crate) const BASE_MAINNET_RELAYER_FEE_RECIPIENT: Address =
-    address!("0x0000000000000000000000000000000000000000");
-/// The relayer fee recipient address on Base Sepolia
-pub(crate) const BASE_SEPOLIA_RELAYER_FEE_RECIPIENT: Address =
-    address!("0xa125ecd644591348d08243d8821120c6d7d3a077");
-/// The relayer fee recipient address on Ethereum Sepolia
-pub(crate) const ETHEREUM_SEPOLIA_RELAYER_FEE_RECIPIENT: Address =
-    address!("0x0000000000000000000000000000000000000000");
+/// The timeout on requests for the relayer's exchange metadata
+const EXCHANGE_METADATA_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// The client config
+///
+/// Generic over the signer holding the account key, which defaults to a local
+/// private key. Any async [`Signer`](alloy::signers::Signer) may be used, e.g.
+/// one backed by a KMS, HSM, or remote signing service.
 #[derive(Debug, Clone)]
-pub struct RenegadeClientConfig {
+pub struct RenegadeClientConfig<S = PrivateKeySigner> {
     /// The relayer base URL
     pub relayer_base_url: String,
     /// The historical state base URL
     pub historical_state_base_url: String,
     /// The chain ID
     pub chain_id: u64,
+    /// The environment-agnostic chain name, used to route historical state
+    /// requests
+    pub chain_name: String,
     /// The darkpool contract address
     pub darkpool_address: Address,
     /// The permit2 contract address
@@ -115,160 +42,288 @@ pub struct RenegadeClientConfig {
     pub executor_address: Address,
     /// The relayer's fee recipient address
     pub relayer_fee_recipient: Address,
-    /// The private key from which to derive the wallet
-    pub key: PrivateKeySigner,
+    /// The wrapped ether contract address
+    pub weth_address: Address,
+    /// Whether the darkpool is deployed as a solidity contract
+    pub is_solidity_chain: bool,
+    /// The signer from which to derive the wallet
+    pub key: S,
     /// The HMAC key used to authenticate admin API actions
     pub admin_hmac_key: Option<HmacKey>,
+    /// The index of the account to derive from the signer
+    ///
+    /// Each index derives an independent account under the same key
+    pub account_index: u32,
 }
 
-impl RenegadeClientConfig {
-    /// Create a new client config for Arbitrum One
-    pub fn new_arbitrum_one(key: &PrivateKeySigner) -> Self {
+impl<S: Clone> RenegadeClientConfig<S> {
+    /// Create a new client config for the given network
+    pub fn from_network(network: impl Into<NetworkConfig>, key: &S) -> Self {
+        let network = network.into();
         Self {
-            relayer_base_url: ARBITRUM_ONE_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: MAINNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: ARBITRUM_ONE_CHAIN_ID,
-            darkpool_address: ARBITRUM_ONE_DARKPOOL_ADDRESS,
-            permit2_address: ARBITRUM_ONE_PERMIT2_ADDRESS,
-            executor_address: ARBITRUM_ONE_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: ARBITRUM_ONE_RELAYER_FEE_RECIPIENT,
+            relayer_base_url: network.relayer_base_url,
+            historical_state_base_url: network.historical_state_base_url,
+            chain_id: network.chain_id,
+            chain_name: network.chain_name,
+            darkpool_address: network.darkpool_address,
+            permit2_address: network.permit2_address,
+            executor_address: network.executor_address,
+            relayer_fee_recipient: network.relayer_fee_recipient,
+            weth_address: network.weth_address,
+            is_solidity_chain: network.is_solidity_chain,
             key: key.clone(),
             admin_hmac_key: None,
+            account_index: DEFAULT_ACCOUNT_INDEX,
         }
     }
 
+    /// Create a new admin client config for the given network
+    pub fn from_network_admin(
+        network: impl Into<NetworkConfig>,
+        key: &S,
+        admin_hmac_key: HmacKey,
+    ) -> Self {
+        Self { admin_hmac_key: Some(admin_hmac_key), ..Self::from_network(network, key) }
+    }
+
+    /// Create a new client config for Arbitrum One
+    pub fn new_arbitrum_one(key: &S) -> Self {
+        Self::from_network(Network::ArbitrumOne, key)
+    }
+
     /// Create a new admin client config for Arbitrum One
-    pub fn new_arbitrum_one_admin(key: &PrivateKeySigner, admin_hmac_key: HmacKey) -> Self {
-        Self {
-            relayer_base_url: ARBITRUM_ONE_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: MAINNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: ARBITRUM_ONE_CHAIN_ID,
-            darkpool_address: ARBITRUM_ONE_DARKPOOL_ADDRESS,
-            permit2_address: ARBITRUM_ONE_PERMIT2_ADDRESS,
-            executor_address: ARBITRUM_ONE_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: ARBITRUM_ONE_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: Some(admin_hmac_key),
-        }
+    pub fn new_arbitrum_one_admin(key: &S, admin_hmac_key: HmacKey) -> Self {
+        Self::from_network_admin(Network::ArbitrumOne, key, admin_hmac_key)
     }
 
     /// Create a new client config for Arbitrum Sepolia
-    pub fn new_arbitrum_sepolia(key: &PrivateKeySigner) -> Self {
-        Self {
-            relayer_base_url: ARBITRUM_SEPOLIA_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: TESTNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: ARBITRUM_SEPOLIA_CHAIN_ID,
-            darkpool_address: ARBITRUM_SEPOLIA_DARKPOOL_ADDRESS,
-            permit2_address: ARBITRUM_SEPOLIA_PERMIT2_ADDRESS,
-            executor_address: ARBITRUM_SEPOLIA_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: ARBITRUM_SEPOLIA_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: None,
-        }
+    pub fn new_arbitrum_sepolia(key: &S) -> Self {
+        Self::from_network(Network::ArbitrumSepolia, key)
     }
 
     /// Create a new admin client config for Arbitrum Sepolia
-    pub fn new_arbitrum_sepolia_admin(key: &PrivateKeySigner, admin_hmac_key: HmacKey) -> Self {
-        Self {
-            relayer_base_url: ARBITRUM_SEPOLIA_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: TESTNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: ARBITRUM_SEPOLIA_CHAIN_ID,
-            darkpool_address: ARBITRUM_SEPOLIA_DARKPOOL_ADDRESS,
-            permit2_address: ARBITRUM_SEPOLIA_PERMIT2_ADDRESS,
-            executor_address: ARBITRUM_SEPOLIA_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: ARBITRUM_SEPOLIA_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: Some(admin_hmac_key),
-        }
+    pub fn new_arbitrum_sepolia_admin(key: &S, admin_hmac_key: HmacKey) -> Self {
+        Self::from_network_admin(Network::ArbitrumSepolia, key, admin_hmac_key)
     }
 
     /// Create a new client config for Base Mainnet
-    pub fn new_base_mainnet(key: &PrivateKeySigner) -> Self {
-        Self {
-            relayer_base_url: BASE_MAINNET_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: MAINNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: BASE_MAINNET_CHAIN_ID,
-            darkpool_address: BASE_MAINNET_DARKPOOL_ADDRESS,
-            permit2_address: BASE_MAINNET_PERMIT2_ADDRESS,
-            executor_address: BASE_MAINNET_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: BASE_MAINNET_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: None,
-        }
+    pub fn new_base_mainnet(key: &S) -> Self {
+        Self::from_network(Network::BaseMainnet, key)
     }
 
     /// Create a new admin client config for Base Mainnet
-    pub fn new_base_mainnet_admin(key: &PrivateKeySigner, admin_hmac_key: HmacKey) -> Self {
-        Self {
-            relayer_base_url: BASE_MAINNET_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: MAINNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: BASE_MAINNET_CHAIN_ID,
-            darkpool_address: BASE_MAINNET_DARKPOOL_ADDRESS,
-            permit2_address: BASE_MAINNET_PERMIT2_ADDRESS,
-            executor_address: BASE_MAINNET_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: BASE_MAINNET_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: Some(admin_hmac_key),
-        }
+    pub fn new_base_mainnet_admin(key: &S, admin_hmac_key: HmacKey) -> Self {
+        Self::from_network_admin(Network::BaseMainnet, key, admin_hmac_key)
     }
 
     /// Create a new client config for Base Sepolia
-    pub fn new_base_sepolia(key: &PrivateKeySigner) -> Self {
-        Self {
-            relayer_base_url: BASE_SEPOLIA_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: TESTNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: BASE_SEPOLIA_CHAIN_ID,
-            darkpool_address: BASE_SEPOLIA_DARKPOOL_ADDRESS,
-            permit2_address: BASE_SEPOLIA_PERMIT2_ADDRESS,
-            executor_address: BASE_SEPOLIA_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: BASE_SEPOLIA_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: None,
-        }
+    pub fn new_base_sepolia(key: &S) -> Self {
+        Self::from_network(Network::BaseSepolia, key)
     }
 
     /// Create a new admin client config for Base Sepolia
-    pub fn new_base_sepolia_admin(key: &PrivateKeySigner, admin_hmac_key: HmacKey) -> Self {
-        Self {
-            relayer_base_url: BASE_SEPOLIA_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: TESTNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: BASE_SEPOLIA_CHAIN_ID,
-            darkpool_address: BASE_SEPOLIA_DARKPOOL_ADDRESS,
-            permit2_address: BASE_SEPOLIA_PERMIT2_ADDRESS,
-            executor_address: BASE_SEPOLIA_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: BASE_SEPOLIA_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: Some(admin_hmac_key),
-        }
+    pub fn new_base_sepolia_admin(key: &S, admin_hmac_key: HmacKey) -> Self {
+        Self::from_network_admin(Network::BaseSepolia, key, admin_hmac_key)
     }
 
     /// Create a new client config for Ethereum Sepolia
-    pub fn new_ethereum_sepolia(key: &PrivateKeySigner) -> Self {
-        Self {
-            relayer_base_url: ETHEREUM_SEPOLIA_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: TESTNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: ETHEREUM_SEPOLIA_CHAIN_ID,
-            darkpool_address: ETHEREUM_SEPOLIA_DARKPOOL_ADDRESS,
-            permit2_address: ETHEREUM_SEPOLIA_PERMIT2_ADDRESS,
-            executor_address: ETHEREUM_SEPOLIA_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: ETHEREUM_SEPOLIA_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: None,
-        }
+    pub fn new_ethereum_sepolia(key: &S) -> Self {
+        Self::from_network(Network::EthereumSepolia, key)
     }
 
     /// Create a new admin client config for Ethereum Sepolia
-    pub fn new_ethereum_sepolia_admin(key: &PrivateKeySigner, admin_hmac_key: HmacKey) -> Self {
-        Self {
-            relayer_base_url: ETHEREUM_SEPOLIA_RELAYER_BASE_URL.to_string(),
-            historical_state_base_url: TESTNET_HISTORICAL_STATE_BASE_URL.to_string(),
-            chain_id: ETHEREUM_SEPOLIA_CHAIN_ID,
-            darkpool_address: ETHEREUM_SEPOLIA_DARKPOOL_ADDRESS,
-            permit2_address: ETHEREUM_SEPOLIA_PERMIT2_ADDRESS,
-            executor_address: ETHEREUM_SEPOLIA_EXECUTOR_ADDRESS,
-            relayer_fee_recipient: ETHEREUM_SEPOLIA_RELAYER_FEE_RECIPIENT,
-            key: key.clone(),
-            admin_hmac_key: Some(admin_hmac_key),
+    pub fn new_ethereum_sepolia_admin(key: &S, admin_hmac_key: HmacKey) -> Self {
+        Self::from_network_admin(Network::EthereumSepolia, key, admin_hmac_key)
+    }
+
+    /// Create a new client config from a relayer's exchange metadata
+    ///
+    /// The darkpool, executor and fee recipient addresses are taken from the
+    /// relayer rather than hard-coded. Other parameters are taken from the
+    /// hosted network matching the relayer's chain ID, or from
+    /// [`NetworkConfig::custom`] if the chain is not a hosted network.
+    pub async fn from_relayer(
+        relayer_base_url: &str,
+        key: &S,
+    ) -> Result<Self, RenegadeClientError> {
+        let metadata = fetch_exchange_metadata(relayer_base_url).await?;
+        let RelayerAddresses { darkpool_address, executor_address, relayer_fee_recipient } =
+            RelayerAddresses::from_metadata(&metadata)?;
+
+        let network = match NetworkConfig::from_chain_id(metadata.chain_id) {
+            Ok(network) => network,
+            Err(_) => NetworkConfig::custom(
+                metadata.chain_id,
+                relayer_base_url,
+                darkpool_address,
+                CANONICAL_PERMIT2_ADDRESS,
+                executor_address,
+            ),
+        };
+
+        Ok(Self {
+            relayer_base_url: relayer_base_url.to_string(),
+            darkpool_address,
+            executor_address,
+            relayer_fee_recipient,
+            ..Self::from_network(network, key)
+        })
+    }
+}
+
+impl<S> RenegadeClientConfig<S> {
+    /// Replace the signer holding the account key
+    pub fn with_signer<T>(self, key: T) -> RenegadeClientConfig<T> {
+        RenegadeClientConfig {
+            relayer_base_url: self.relayer_base_url,
+            historical_state_base_url: self.historical_state_base_url,
+            chain_id: self.chain_id,
+            chain_name: self.chain_name,
+            darkpool_address: self.darkpool_address,
+            permit2_address: self.permit2_address,
+            executor_address: self.executor_address,
+            relayer_fee_recipient: self.relayer_fee_recipient,
+            weth_address: self.weth_address,
+            is_solidity_chain: self.is_solidity_chain,
+            key,
+            admin_hmac_key: self.admin_hmac_key,
+            account_index: self.account_index,
+        }
+    }
+
+    /// Set the index of the account to derive from the signer
+    pub fn with_account_index(self, account_index: u32) -> Self {
+        Self { account_index, ..self }
+    }
+
+    /// Check the config against the relayer's exchange metadata
+    ///
+    /// Returns an error listing each of the chain ID, darkpool, executor and
+    /// fee recipient values that disagree with the live relayer
+    pub async fn verify_against_metadata(&self) -> Result<(), RenegadeClientError> {
+        let metadata = fetch_exchange_metadata(&self.relayer_base_url).await?;
+        let relayer = RelayerAddresses::from_metadata(&metadata)?;
+
+        let mut mismatches = Vec::new();
+        if self.chain_id != metadata.chain_id {
+            mismatches.push(mismatch("chain_id", self.chain_id, metadata.chain_id));
         }
+        if self.darkpool_address != relayer.darkpool_address {
+            mismatches.push(mismatch(
+                "darkpool_address",
+                self.darkpool_address,
+                relayer.darkpool_address,
+            ));
+        }
+        if self.executor_address != relayer.executor_address {
+            mismatches.push(mismatch(
+                "executor_address",
+                self.executor_address,
+                relayer.executor_address,
+            ));
+        }
+        if self.relayer_fee_recipient != relayer.relayer_fee_recipient {
+            mismatches.push(mismatch(
+                "relayer_fee_recipient",
+                self.relayer_fee_recipient,
+                relayer.relayer_fee_recipient,
+            ));
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(RenegadeClientError::config(mismatches.join("; ")))
+        }
+    }
+}
+
+#[cfg(feature = "settings")]
+impl RenegadeClientConfig {
+    /// Create a new client config from loaded settings
+    ///
+    /// Requires a network and key source, and uses the admin HMAC key if one is
+    /// set
+    pub fn from_settings(settings: &ClientSettings) -> Result<Self, ConfigError> {
+        let network = settings.network_config()?;
+        let key = settings.signer()?;
+        let admin_hmac_key = settings.admin_hmac_key()?;
+
+        Ok(Self { admin_hmac_key, ..Self::from_network(network, &key) })
     }
+
+    /// Create a new client config from environment variables
+    ///
+    /// See [`crate::settings`] for the variables read
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_settings(&ClientSettings::from_env()?)
+    }
+
+    /// Create a new client config from a TOML or JSON file
+    ///
+    /// See [`crate::settings`] for the keys read
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        Self::from_settings(&ClientSettings::from_file(path)?)
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// The addresses reported in a relayer's exchange metadata
+struct RelayerAddresses {
+    /// The darkpool contract address
+    darkpool_address: Address,
+    /// The relayer's executor address
+    executor_address: Address,
+    /// The relayer's fee recipient address
+    relayer_fee_recipient: Address,
+}
+
+impl RelayerAddresses {
+    /// Parse the addresses from the exchange metadata
+    fn from_metadata(metadata: &ExchangeMetadataResponse) -> Result<Self, RenegadeClientError> {
+        Ok(Self {
+            darkpool_address: parse_address(&metadata.settlement_contract_address)?,
+            executor_address: parse_address(&metadata.executor_address)?,
+            relayer_fee_recipient: parse_address(&metadata.relayer_fee_recipient)?,
+        })
+    }
+}
+
+/// Fetch the exchange metadata from a relayer
+///
+/// No account key is known before the network is, so the request is signed
+/// with a throwaway key. If the relayer rejects it, the network must be
+/// configured explicitly, e.g. with [`NetworkConfig::custom`].
+async fn fetch_exchange_metadata(
+    relayer_base_url: &str,
+) -> Result<ExchangeMetadataResponse, RenegadeClientError> {
+    let http_client = reqwest::Client::builder()
+        .timeout(EXCHANGE_METADATA_TIMEOUT)
+        .build()
+        .map_err(RenegadeClientError::config)?;
+    let client = RelayerHttpClient::new_with_client(
+        relayer_base_url.to_string(),
+        HmacKey::random(),
+        http_client,
+    );
+
+    client.get(GET_EXCHANGE_METADATA_ROUTE).await.map_err(|e| {
+        RenegadeClientError::config(format!(
+            "failed to fetch exchange metadata from {relayer_base_url}: {e}"
+        ))
+    })
+}
+
+/// Parse an address reported by the relayer
+fn parse_address(address: &str) -> Result<Address, RenegadeClientError> {
+    Address::from_str(address)
+        .map_err(|e| RenegadeClientError::config(format!("invalid address {address}: {e}")))
+}
+
+/// Describe a value that disagrees with the relayer
+fn mismatch<T: std::fmt::Display>(field: &str, configured: T, relayer: T) -> String {
+    format!("{field} is {configured} but the relayer reports {relayer}")
 }
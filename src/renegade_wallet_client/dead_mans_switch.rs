@@ -0,0 +1,352 @@
+//! A dead man's switch that cancels all open orders on lost liveness
+//!
+//! Resting orders keep filling while the application that placed them is hung
+//! or blind to the market. A [`DeadMansSwitch`] watches two liveness signals:
+//! heartbeats sent by the application, & the client's websocket connection. If
+//! the application misses its heartbeat deadline, or the websocket stays down
+//! for too long, the switch trips: it emits a [`DeadMansSwitchEvent::Tripping`]
+//! event, cancels all of the account's open orders in the background, then
+//! emits a [`DeadMansSwitchEvent::Tripped`] event describing what it did.
+//!
+//! A tripped switch stays disarmed until the application heartbeats again.
+//! Tripping the switch does not prevent new orders from being placed.
+
+use std::{sync::Arc, time::Duration};
+
+use alloy::signers::Signer;
+use futures_util::{Stream, StreamExt};
+use tokio::{
+    sync::{Mutex, Notify, broadcast, watch},
+    task::JoinHandle,
+    time::{Instant, sleep, sleep_until},
+};
+use tokio_stream::wrappers::BroadcastStream;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::client::RenegadeClient;
+
+// -------------
+// | Constants |
+// -------------
+
+/// The capacity of the event channel
+const EVENTS_CHANNEL_CAPACITY: usize = 100;
+/// The number of attempts made to fetch the open orders when tripped
+const MAX_FETCH_ATTEMPTS: u32 = 5;
+/// The delay between attempts to fetch the open orders when tripped
+const FETCH_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+// ---------
+// | Types |
+// ---------
+
+/// The configuration of a dead man's switch
+#[derive(Clone, Copy, Debug)]
+pub struct DeadMansSwitchConfig {
+    /// The longest the application may go without heartbeating
+    pub heartbeat_timeout: Duration,
+    /// The longest the websocket connection may stay down
+    pub disconnect_timeout: Duration,
+}
+
+impl DeadMansSwitchConfig {
+    /// Create a new config
+    pub fn new(heartbeat_timeout: Duration, disconnect_timeout: Duration) -> Self {
+        Self { heartbeat_timeout, disconnect_timeout }
+    }
+}
+
+/// Why a dead man's switch tripped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TripReason {
+    /// The application did not heartbeat within the timeout
+    HeartbeatMissed {
+        /// The time since the last heartbeat
+        since_last_heartbeat: Duration,
+    },
+    /// The websocket connection stayed down for longer than the timeout
+    Disconnected {
+        /// The time the connection had been down
+        down_for: Duration,
+    },
+}
+
+/// An action taken by a dead man's switch
+#[derive(Clone, Debug)]
+pub enum DeadMansSwitchEvent {
+    /// The switch tripped & started cancelling the account's open orders
+    Tripping {
+        /// Why the switch tripped
+        reason: TripReason,
+    },
+    /// The switch finished cancelling the account's open orders after
+    /// tripping
+    Tripped {
+        /// Why the switch tripped
+        reason: TripReason,
+        /// The orders for which a cancellation was enqueued
+        cancelled: Vec<Uuid>,
+        /// The orders whose cancellation failed, with the error
+        failed: Vec<(Uuid, String)>,
+        /// The error fetching the open orders, if every attempt failed, in
+        /// which case no orders were cancelled
+        fetch_error: Option<String>,
+    },
+    /// The switch was re-armed by a heartbeat after tripping
+    Rearmed,
+}
+
+// ---------------------
+// | Dead Man's Switch |
+// ---------------------
+
+/// A handle to a dead man's switch
+///
+/// Cloned handles share the same switch. The switch is disabled once all
+/// handles are dropped.
+#[derive(Clone)]
+pub struct DeadMansSwitch {
+    /// The state shared with the watchdog task
+    shared: Arc<SwitchState>,
+    /// The task watching the liveness signals
+    _watchdog: Arc<WatchdogTask>,
+}
+
+/// The state shared between handles & the watchdog task
+struct SwitchState {
+    /// The switch's configuration
+    config: DeadMansSwitchConfig,
+    /// The liveness of the application
+    liveness: Mutex<Liveness>,
+    /// Wakes the watchdog when the application heartbeats
+    heartbeat: Notify,
+    /// The channel on which events are broadcast
+    events_tx: broadcast::Sender<DeadMansSwitchEvent>,
+}
+
+/// The application's liveness, as seen by the switch
+struct Liveness {
+    /// Whether the switch is armed
+    armed: bool,
+    /// When the switch was last armed
+    armed_at: Instant,
+    /// When the application last heartbeat
+    last_heartbeat: Instant,
+}
+
+/// A handle to the watchdog task, which aborts the task when dropped
+struct WatchdogTask(JoinHandle<()>);
+
+impl Drop for WatchdogTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl DeadMansSwitch {
+    /// Record a heartbeat from the application, re-arming the switch if it has
+    /// tripped
+    pub async fn heartbeat(&self) {
+        let now = Instant::now();
+        let rearmed = {
+            let mut liveness = self.shared.liveness.lock().await;
+            liveness.last_heartbeat = now;
+            let rearmed = !liveness.armed;
+            if rearmed {
+                liveness.armed = true;
+                liveness.armed_at = now;
+            }
+
+            rearmed
+        };
+
+        if rearmed {
+            // There may be no listeners, which is not an error
+            let _ = self.shared.events_tx.send(DeadMansSwitchEvent::Rearmed);
+        }
+        self.shared.heartbeat.notify_one();
+    }
+
+    /// Whether the switch is armed, i.e. has not tripped since the last
+    /// heartbeat
+    pub async fn is_armed(&self) -> bool {
+        self.shared.liveness.lock().await.armed
+    }
+
+    /// Stream the actions taken by the switch
+    ///
+    /// Events emitted before the stream is created are not replayed
+    pub fn events(&self) -> impl Stream<Item = DeadMansSwitchEvent> + use<> {
+        BroadcastStream::new(self.shared.events_tx.subscribe())
+            .filter_map(|event| async move { event.ok() })
+    }
+}
+
+// ------------
+// | Watchdog |
+// ------------
+
+impl SwitchState {
+    /// Trip the switch when either liveness signal lapses
+    async fn run_watchdog<S: Signer + Clone + 'static>(
+        self: Arc<Self>,
+        client: RenegadeClient<S>,
+        mut connection: watch::Receiver<bool>,
+    ) {
+        let mut disconnected_at = (!*connection.borrow()).then(Instant::now);
+        loop {
+            let deadline = {
+                let liveness = self.liveness.lock().await;
+                liveness.armed.then(|| self.deadline(&liveness, disconnected_at))
+            };
+
+            tokio::select! {
+                _ = sleep_until_deadline(deadline) => {
+                    if let Some(reason) = self.check_liveness(disconnected_at).await {
+                        self.trip(&client, reason);
+                    }
+                },
+                changed = connection.changed() => match changed {
+                    Ok(()) => {
+                        let connected = *connection.borrow_and_update();
+                        disconnected_at = match (connected, disconnected_at) {
+                            (true, _) => None,
+                            (false, Some(at)) => Some(at),
+                            (false, None) => Some(Instant::now()),
+                        };
+                    },
+                    Err(_) => break,
+                },
+                // The deadline moved
+                _ = self.heartbeat.notified() => {},
+            }
+        }
+    }
+
+    /// The instant at which the first liveness signal lapses
+    fn deadline(&self, liveness: &Liveness, disconnected_at: Option<Instant>) -> Instant {
+        let heartbeat_deadline = liveness.last_heartbeat + self.config.heartbeat_timeout;
+        match disconnected_at {
+            // Time spent disconnected before the switch was armed does not count
+            Some(at) => {
+                let disconnect_deadline =
+                    at.max(liveness.armed_at) + self.config.disconnect_timeout;
+                heartbeat_deadline.min(disconnect_deadline)
+            },
+            None => heartbeat_deadline,
+        }
+    }
+
+    /// Check whether either liveness signal has lapsed, disarming the switch if
+    /// so
+    async fn check_liveness(&self, disconnected_at: Option<Instant>) -> Option<TripReason> {
+        let mut liveness = self.liveness.lock().await;
+        let now = Instant::now();
+        if !liveness.armed || now < self.deadline(&liveness, disconnected_at) {
+            return None;
+        }
+
+        liveness.armed = false;
+        let since_last_heartbeat = now - liveness.last_heartbeat;
+        if since_last_heartbeat >= self.config.heartbeat_timeout {
+            return Some(TripReason::HeartbeatMissed { since_last_heartbeat });
+        }
+
+        let down_for = disconnected_at.map(|at| now - at).unwrap_or_default();
+        Some(TripReason::Disconnected { down_for })
+    }
+
+    /// Emit a tripping event, then cancel all open orders in the background
+    ///
+    /// The cancellations run in their own task so that the watchdog keeps
+    /// tracking the liveness signals while they are in flight
+    fn trip<S: Signer + Clone + 'static>(&self, client: &RenegadeClient<S>, reason: TripReason) {
+        warn!("Dead man's switch tripped ({reason:?}), cancelling all open orders");
+        // There may be no listeners, which is not an error
+        let _ = self.events_tx.send(DeadMansSwitchEvent::Tripping { reason });
+
+        let client = client.clone();
+        let events_tx = self.events_tx.clone();
+        tokio::spawn(async move {
+            let event = cancel_open_orders(&client, reason).await;
+            let _ = events_tx.send(event);
+        });
+    }
+}
+
+// ----------------------
+// | Client Integration |
+// ----------------------
+
+impl<S: Signer + Clone + 'static> RenegadeClient<S> {
+    /// Arm a dead man's switch that cancels all open orders if the application
+    /// stops heartbeating, or the websocket connection stays down, for longer
+    /// than the configured timeouts
+    ///
+    /// The application must call [`DeadMansSwitch::heartbeat`] at least once
+    /// per heartbeat timeout for as long as the switch is held.
+    pub fn dead_mans_switch(&self, config: DeadMansSwitchConfig) -> DeadMansSwitch {
+        let now = Instant::now();
+        let (events_tx, _) = broadcast::channel(EVENTS_CHANNEL_CAPACITY);
+        let shared = Arc::new(SwitchState {
+            config,
+            liveness: Mutex::new(Liveness { armed: true, armed_at: now, last_heartbeat: now }),
+            heartbeat: Notify::new(),
+            events_tx,
+        });
+
+        let connection = self.websocket_client.watch_connection();
+        let task = tokio::spawn(shared.clone().run_watchdog(self.clone(), connection));
+        DeadMansSwitch { shared, _watchdog: Arc::new(WatchdogTask(task)) }
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Sleep until the given deadline, or forever if there is none
+async fn sleep_until_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Cancel all open orders, returning an event describing the cancellations
+///
+/// Fetching the open orders is attempted up to `MAX_FETCH_ATTEMPTS` times
+async fn cancel_open_orders<S: Signer>(
+    client: &RenegadeClient<S>,
+    reason: TripReason,
+) -> DeadMansSwitchEvent {
+    let mut attempt = 1;
+    let results = loop {
+        match client.cancel_all_orders(|_| true).await {
+            Ok(results) => break results,
+            Err(e) if attempt == MAX_FETCH_ATTEMPTS => {
+                warn!("Dead man's switch failed to fetch open orders, giving up: {e}");
+                let fetch_error = Some(e.to_string());
+                let (cancelled, failed) = (Vec::new(), Vec::new());
+                return DeadMansSwitchEvent::Tripped { reason, cancelled, failed, fetch_error };
+            },
+            Err(e) => {
+                warn!("Dead man's switch failed to fetch open orders, retrying: {e}");
+                attempt += 1;
+                sleep(FETCH_RETRY_DELAY).await;
+            },
+        }
+    };
+
+    let mut cancelled = Vec::new();
+    let mut failed = Vec::new();
+    for (order_id, res) in results {
+        match res {
+            Ok(_) => cancelled.push(order_id),
+            Err(e) => failed.push((order_id, e.to_string())),
+        }
+    }
+
+    DeadMansSwitchEvent::Tripped { reason, cancelled, failed, fetch_error: None }
+}
@@ -0,0 +1,470 @@
+//! Client-side order expiry
+//!
+//! Orders carry no expiry in the relayer, so good-till-time orders are
+//! cancelled by a local scheduler at their deadline. The scheduler runs as a
+//! background task once enabled with [`RenegadeClient::enable_order_expiry`],
+//! & orders built with [`OrderBuilder::with_expiry`] are registered with it
+//! when placed.
+//!
+//! A cancellation that fails, e.g. while the relayer is unreachable, is retried
+//! until it succeeds or the order is no longer open. Expiries may be backed by
+//! an [`ExpiryStore`] so that they survive process restarts; expiries loaded
+//! from the store whose deadline has passed are cancelled immediately.
+//!
+//! [`OrderBuilder::with_expiry`]: crate::actions::place_order::OrderBuilder::with_expiry
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    pin::Pin,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use alloy::signers::Signer;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::Notify,
+    task::JoinHandle,
+    time::{Instant, sleep_until},
+};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    RenegadeClientError,
+    client::RenegadeClient,
+    renegade_wallet_client::{persistence::JsonDir, utils::unix_now},
+};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The delay before retrying a cancellation that failed while the order was
+/// still open
+const CANCEL_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+// ---------
+// | Types |
+// ---------
+
+/// When an order expires
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expiry {
+    /// The order expires the given duration after it is placed
+    After(Duration),
+    /// The order expires at the given Unix timestamp, in seconds
+    At(u64),
+}
+
+impl Expiry {
+    /// The Unix timestamp at which the order expires, if placed now
+    pub(crate) fn deadline(&self) -> Result<u64, RenegadeClientError> {
+        match self {
+            Expiry::After(duration) => Ok(unix_now()? + ceil_secs(*duration)),
+            Expiry::At(timestamp) => Ok(*timestamp),
+        }
+    }
+}
+
+impl From<Duration> for Expiry {
+    fn from(duration: Duration) -> Self {
+        Expiry::After(duration)
+    }
+}
+
+impl From<SystemTime> for Expiry {
+    fn from(time: SystemTime) -> Self {
+        let timestamp = time.duration_since(UNIX_EPOCH).map(ceil_secs).unwrap_or_default();
+        Expiry::At(timestamp)
+    }
+}
+
+/// A scheduled order expiry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderExpiry {
+    /// The ID of the order
+    pub order_id: Uuid,
+    /// The Unix timestamp at which the order is cancelled, in seconds
+    pub deadline: u64,
+}
+
+// ----------------
+// | Expiry Store |
+// ----------------
+
+/// Storage for scheduled order expiries
+pub trait ExpiryStore: Send + Sync {
+    /// Save an expiry, replacing any previously saved expiry for the order
+    fn save(&self, expiry: &OrderExpiry) -> Result<(), RenegadeClientError>;
+    /// Load all saved expiries
+    fn list(&self) -> Result<Vec<OrderExpiry>, RenegadeClientError>;
+    /// Delete the expiry of an order
+    fn remove(&self, order_id: Uuid) -> Result<(), RenegadeClientError>;
+}
+
+/// An expiry store holding one JSON file per order in a directory
+#[derive(Clone, Debug)]
+pub struct FileExpiryStore {
+    /// The directory holding the expiries
+    dir: JsonDir,
+}
+
+impl FileExpiryStore {
+    /// Open a store in the given directory, creating it if it does not exist
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, RenegadeClientError> {
+        Ok(Self { dir: JsonDir::open(dir)? })
+    }
+}
+
+impl ExpiryStore for FileExpiryStore {
+    fn save(&self, expiry: &OrderExpiry) -> Result<(), RenegadeClientError> {
+        self.dir.save(expiry.order_id, expiry)
+    }
+
+    fn list(&self) -> Result<Vec<OrderExpiry>, RenegadeClientError> {
+        self.dir.list()
+    }
+
+    fn remove(&self, order_id: Uuid) -> Result<(), RenegadeClientError> {
+        self.dir.remove(order_id)
+    }
+}
+
+// --------------------
+// | Expiry Scheduler |
+// --------------------
+
+/// A future cancelling an order
+type CancelFuture = Pin<Box<dyn Future<Output = Result<(), RenegadeClientError>> + Send>>;
+/// Cancels an order by ID
+type CancelFn = Box<dyn Fn(Uuid) -> CancelFuture + Send + Sync>;
+/// Lists the IDs of the account's open orders
+type OpenOrdersFuture =
+    Pin<Box<dyn Future<Output = Result<Vec<Uuid>, RenegadeClientError>> + Send>>;
+/// Fetches the IDs of the account's open orders
+type OpenOrdersFn = Box<dyn Fn() -> OpenOrdersFuture + Send + Sync>;
+
+/// The slot holding a client's expiry scheduler, shared between clones of
+/// the client
+pub(crate) type ExpirySlot = OnceLock<ExpiryScheduler>;
+
+/// A handle to a running expiry scheduler, which stops the scheduler when
+/// dropped
+pub(crate) struct ExpiryScheduler {
+    /// The state shared with the scheduler task
+    shared: Arc<SchedulerState>,
+    /// The scheduler task
+    task: JoinHandle<()>,
+}
+
+impl Drop for ExpiryScheduler {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// The state shared between the scheduler handle & task
+struct SchedulerState {
+    /// The deadline of each scheduled order
+    deadlines: Mutex<HashMap<Uuid, u64>>,
+    /// The store backing the deadlines, if any
+    store: Option<Arc<dyn ExpiryStore>>,
+    /// Wakes the task when the deadlines change
+    wake: Notify,
+    /// Cancels an order
+    cancel: CancelFn,
+    /// Fetches the IDs of the account's open orders
+    open_orders: OpenOrdersFn,
+}
+
+impl ExpiryScheduler {
+    /// Schedule an order to be cancelled at the given deadline
+    pub(crate) fn schedule(
+        &self,
+        order_id: Uuid,
+        deadline: u64,
+    ) -> Result<(), RenegadeClientError> {
+        if let Some(store) = &self.shared.store {
+            store.save(&OrderExpiry { order_id, deadline })?;
+        }
+
+        self.shared.deadlines.lock().unwrap().insert(order_id, deadline);
+        self.shared.wake.notify_one();
+        Ok(())
+    }
+
+    /// Remove an order's expiry
+    pub(crate) fn unschedule(&self, order_id: Uuid) -> Result<(), RenegadeClientError> {
+        self.shared.remove(order_id)?;
+        self.shared.wake.notify_one();
+        Ok(())
+    }
+
+    /// Get the scheduled expiries
+    pub(crate) fn expiries(&self) -> Vec<OrderExpiry> {
+        let deadlines = self.shared.deadlines.lock().unwrap();
+        deadlines.iter().map(|(&order_id, &deadline)| OrderExpiry { order_id, deadline }).collect()
+    }
+}
+
+impl SchedulerState {
+    /// Cancel orders as their deadlines pass
+    async fn run(self: Arc<Self>) {
+        loop {
+            let Some((order_id, deadline)) = self.next_deadline() else {
+                self.wake.notified().await;
+                continue;
+            };
+
+            let wait = deadline.saturating_sub(unix_now().unwrap_or(deadline));
+            tokio::select! {
+                _ = sleep_until(Instant::now() + Duration::from_secs(wait)) => {
+                    self.expire(order_id).await;
+                },
+                // The deadlines changed, so the next deadline may have too
+                _ = self.wake.notified() => {},
+            }
+        }
+    }
+
+    /// The earliest scheduled deadline
+    fn next_deadline(&self) -> Option<(Uuid, u64)> {
+        let deadlines = self.deadlines.lock().unwrap();
+        deadlines
+            .iter()
+            .min_by_key(|(_, deadline)| **deadline)
+            .map(|(&id, &deadline)| (id, deadline))
+    }
+
+    /// Cancel an expired order
+    ///
+    /// If the cancellation fails & the order may still be open, it is retried
+    /// after a delay
+    async fn expire(&self, order_id: Uuid) {
+        let err = match (self.cancel)(order_id).await {
+            Ok(()) => return self.remove_or_warn(order_id),
+            Err(e) => e,
+        };
+
+        match (self.open_orders)().await {
+            Ok(open_orders) if !open_orders.contains(&order_id) => {
+                self.remove_or_warn(order_id);
+            },
+            _ => {
+                warn!("Failed to cancel expired order {order_id}, retrying: {err}");
+                let retry_at = unix_now().unwrap_or_default() + CANCEL_RETRY_DELAY.as_secs();
+                self.deadlines.lock().unwrap().insert(order_id, retry_at);
+            },
+        }
+    }
+
+    /// Remove an order's expiry
+    fn remove(&self, order_id: Uuid) -> Result<(), RenegadeClientError> {
+        self.deadlines.lock().unwrap().remove(&order_id);
+        match &self.store {
+            Some(store) => store.remove(order_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Remove an order's expiry, logging any error removing it from the store
+    fn remove_or_warn(&self, order_id: Uuid) {
+        if let Err(e) = self.remove(order_id) {
+            warn!("Failed to remove expiry of order {order_id}: {e}");
+        }
+    }
+}
+
+// ----------------------
+// | Client Integration |
+// ----------------------
+
+impl<S: Signer + Clone + 'static> RenegadeClient<S> {
+    /// Start the scheduler that cancels orders at their expiry
+    ///
+    /// Expiries are saved to the given store, if any, & any expiries already in
+    /// the store are scheduled. The scheduler is shared by all clones of the
+    /// client, & stops when they are all dropped.
+    pub fn enable_order_expiry(
+        &self,
+        store: Option<Arc<dyn ExpiryStore>>,
+    ) -> Result<(), RenegadeClientError> {
+        let deadlines = match &store {
+            Some(store) => store.list()?.into_iter().map(|e| (e.order_id, e.deadline)).collect(),
+            None => HashMap::new(),
+        };
+
+        // The task's client must not hold the scheduler, or the scheduler would
+        // keep itself alive
+        let mut client = self.clone();
+        client.expiry_scheduler = Arc::default();
+        let cancel_client = client.clone();
+        let cancel: CancelFn = Box::new(move |order_id| {
+            let client = cancel_client.clone();
+            Box::pin(async move { client.cancel_order(order_id).await })
+        });
+        let open_orders: OpenOrdersFn = Box::new(move || {
+            let client = client.clone();
+            Box::pin(async move {
+                let orders = client.get_orders(false /* include_historic_orders */).await?;
+                Ok(orders.into_iter().map(|order| order.order.id).collect())
+            })
+        });
+
+        let shared = Arc::new(SchedulerState {
+            deadlines: Mutex::new(deadlines),
+            store,
+            wake: Notify::new(),
+            cancel,
+            open_orders,
+        });
+        let task = tokio::spawn(shared.clone().run());
+
+        self.expiry_scheduler
+            .set(ExpiryScheduler { shared, task })
+            .map_err(|_| RenegadeClientError::custom("order expiry is already enabled"))
+    }
+}
+
+impl<S: Signer> RenegadeClient<S> {
+    /// Schedule an open order to be cancelled at the given expiry
+    ///
+    /// Requires the expiry scheduler to be enabled
+    pub fn schedule_order_expiry(
+        &self,
+        order_id: Uuid,
+        expiry: impl Into<Expiry>,
+    ) -> Result<(), RenegadeClientError> {
+        self.get_expiry_scheduler()?.schedule(order_id, expiry.into().deadline()?)
+    }
+
+    /// Remove an order's scheduled expiry, leaving the order open
+    pub fn cancel_order_expiry(&self, order_id: Uuid) -> Result<(), RenegadeClientError> {
+        self.get_expiry_scheduler()?.unschedule(order_id)
+    }
+
+    /// Remove the expiry of an order the client has cancelled, if the
+    /// scheduler is enabled
+    pub(crate) fn remove_cancelled_order_expiry(&self, order_id: Uuid) {
+        if let Some(scheduler) = self.expiry_scheduler.get()
+            && let Err(e) = scheduler.unschedule(order_id)
+        {
+            warn!("Failed to remove expiry of cancelled order {order_id}: {e}");
+        }
+    }
+
+    /// Get the scheduled order expiries
+    pub fn order_expiries(&self) -> Vec<OrderExpiry> {
+        self.expiry_scheduler.get().map(ExpiryScheduler::expiries).unwrap_or_default()
+    }
+
+    /// Get the expiry scheduler, returning an error if it is not enabled
+    pub(crate) fn get_expiry_scheduler(&self) -> Result<&ExpiryScheduler, RenegadeClientError> {
+        self.expiry_scheduler.get().ok_or_else(|| {
+            RenegadeClientError::custom("order expiry requires `enable_order_expiry`")
+        })
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// The number of whole seconds in a duration, rounded up, so that an order
+/// never expires before its deadline
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a scheduler state with stubbed callbacks
+    ///
+    /// Cancellations succeed if `cancel_succeeds` is set, & `open_orders` is
+    /// returned as the open orders, or an error if `None`
+    fn scheduler(cancel_succeeds: bool, open_orders: Option<Vec<Uuid>>) -> SchedulerState {
+        let cancel: CancelFn = Box::new(move |_| {
+            Box::pin(async move {
+                if cancel_succeeds {
+                    Ok(())
+                } else {
+                    Err(RenegadeClientError::custom("relayer unreachable"))
+                }
+            })
+        });
+        let open_orders: OpenOrdersFn = Box::new(move || {
+            let open_orders = open_orders.clone();
+            Box::pin(async move {
+                open_orders.ok_or_else(|| RenegadeClientError::custom("relayer unreachable"))
+            })
+        });
+
+        SchedulerState {
+            deadlines: Mutex::new(HashMap::new()),
+            store: None,
+            wake: Notify::new(),
+            cancel,
+            open_orders,
+        }
+    }
+
+    /// Schedule an order on the given state with a deadline that has passed
+    fn schedule_expired(state: &SchedulerState) -> Uuid {
+        let order_id = Uuid::new_v4();
+        state.deadlines.lock().unwrap().insert(order_id, 0);
+        order_id
+    }
+
+    #[test]
+    fn test_ceil_secs_rounds_up() {
+        assert_eq!(ceil_secs(Duration::ZERO), 0);
+        assert_eq!(ceil_secs(Duration::from_secs(2)), 2);
+        assert_eq!(ceil_secs(Duration::from_millis(1)), 1);
+        assert_eq!(ceil_secs(Duration::from_millis(1_500)), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expire_removes_cancelled_order() {
+        let state = scheduler(true /* cancel_succeeds */, Some(Vec::new()));
+        let order_id = schedule_expired(&state);
+
+        state.expire(order_id).await;
+        assert!(state.next_deadline().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expire_removes_order_no_longer_open() {
+        let state = scheduler(false /* cancel_succeeds */, Some(Vec::new()));
+        let order_id = schedule_expired(&state);
+
+        state.expire(order_id).await;
+        assert!(state.next_deadline().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expire_retries_open_order() {
+        let order_id = Uuid::new_v4();
+        let state = scheduler(false /* cancel_succeeds */, Some(vec![order_id]));
+        state.deadlines.lock().unwrap().insert(order_id, 0);
+
+        let earliest_retry = unix_now().unwrap() + CANCEL_RETRY_DELAY.as_secs();
+        state.expire(order_id).await;
+
+        let (next_id, retry_at) = state.next_deadline().unwrap();
+        assert_eq!(next_id, order_id);
+        assert!(retry_at >= earliest_retry);
+    }
+
+    #[tokio::test]
+    async fn test_expire_retries_when_open_orders_unknown() {
+        let state = scheduler(false /* cancel_succeeds */, None);
+        let order_id = schedule_expired(&state);
+
+        state.expire(order_id).await;
+        assert_eq!(state.next_deadline().map(|(id, _)| id), Some(order_id));
+    }
+}
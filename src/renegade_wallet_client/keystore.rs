@@ -0,0 +1,334 @@
+//! Encrypted keystores for account secrets
+//!
+//! A keystore holds an account's derived secrets, so that a service may operate
+//! the account without loading the key they were derived from. The secrets are
+//! encrypted with AES-256-GCM under a key stretched from a password with
+//! scrypt, in a JSON layout modeled on Ethereum keystores.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+use aes_gcm::{
+    Aes256Gcm, Key, KeyInit, Nonce,
+    aead::{Aead, Payload},
+};
+use ark_ff::{BigInteger, PrimeField};
+use renegade_circuit_types::schnorr::SchnorrPrivateKey;
+use renegade_constants::{EmbeddedScalarField, Scalar};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+use crate::{HmacKey, RenegadeClientError, auth::HMAC_KEY_LEN, client::AccountSecrets};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The current keystore version
+const KEYSTORE_VERSION: u32 = 1;
+/// The cipher used to encrypt the secrets
+const CIPHER: &str = "aes-256-gcm";
+/// The key derivation function used to stretch the password
+const KDF: &str = "scrypt";
+
+/// The scrypt cost parameter, as a power of two
+///
+/// The scrypt parameters are also the largest accepted when reading a
+/// keystore, so that a crafted file cannot demand unbounded memory or time
+const SCRYPT_LOG_N: u8 = 18;
+/// The scrypt block size parameter
+const SCRYPT_R: u32 = 8;
+/// The scrypt parallelization parameter
+const SCRYPT_P: u32 = 1;
+
+/// The number of bytes in the scrypt salt
+const SALT_BYTES: usize = 32;
+/// The number of bytes in an AES-GCM nonce
+const NONCE_BYTES: usize = 12;
+/// The number of bytes in the AES-256 key
+const KEY_BYTES: usize = 32;
+/// The number of bytes in a serialized field element
+const SCALAR_BYTES: usize = 32;
+/// The number of bytes in the serialized secrets
+const SECRETS_BYTES: usize = 2 * SCALAR_BYTES + HMAC_KEY_LEN;
+
+/// The permissions with which keystore files are created on Unix
+#[cfg(unix)]
+const KEYSTORE_FILE_MODE: u32 = 0o600;
+
+// ---------
+// | Types |
+// ---------
+
+/// An encrypted keystore holding an account's secrets
+#[derive(Serialize, Deserialize)]
+struct AccountKeystore {
+    /// The keystore version
+    version: u32,
+    /// The ID of the account, stored in the clear & authenticated as
+    /// associated data
+    account_id: Uuid,
+    /// The encrypted secrets & the parameters needed to decrypt them
+    crypto: KeystoreCrypto,
+}
+
+/// The encrypted secrets in a keystore
+#[derive(Serialize, Deserialize)]
+struct KeystoreCrypto {
+    /// The cipher used to encrypt the secrets
+    cipher: String,
+    /// The hex-encoded ciphertext, including the authentication tag
+    ciphertext: String,
+    /// The hex-encoded cipher nonce
+    nonce: String,
+    /// The key derivation function used to stretch the password
+    kdf: String,
+    /// The key derivation parameters
+    kdfparams: ScryptParams,
+}
+
+/// The scrypt parameters used to stretch the password
+#[derive(Serialize, Deserialize)]
+struct ScryptParams {
+    /// The cost parameter, as a power of two
+    log_n: u8,
+    /// The block size parameter
+    r: u32,
+    /// The parallelization parameter
+    p: u32,
+    /// The hex-encoded salt
+    salt: String,
+}
+
+// ------------
+// | Keystore |
+// ------------
+
+impl AccountSecrets {
+    /// Encrypt the secrets into a JSON keystore under the given password
+    pub fn to_keystore(&self, password: &str) -> Result<String, RenegadeClientError> {
+        self.to_keystore_with_cost(password, SCRYPT_LOG_N)
+    }
+
+    /// Encrypt the secrets into a JSON keystore, stretching the password with
+    /// the given scrypt cost
+    fn to_keystore_with_cost(
+        &self,
+        password: &str,
+        log_n: u8,
+    ) -> Result<String, RenegadeClientError> {
+        let salt: [u8; SALT_BYTES] = rand::random();
+        let nonce: [u8; NONCE_BYTES] = rand::random();
+        let kdfparams = ScryptParams { log_n, r: SCRYPT_R, p: SCRYPT_P, salt: hex::encode(salt) };
+
+        let plaintext = self.to_bytes();
+        let cipher = build_cipher(password, &kdfparams)?;
+        let payload = Payload { msg: &plaintext, aad: self.account_id.as_bytes() };
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), payload)
+            .map_err(|e| RenegadeClientError::keystore(format!("failed to encrypt: {e}")))?;
+
+        let keystore = AccountKeystore {
+            version: KEYSTORE_VERSION,
+            account_id: self.account_id,
+            crypto: KeystoreCrypto {
+                cipher: CIPHER.to_string(),
+                ciphertext: hex::encode(ciphertext),
+                nonce: hex::encode(nonce),
+                kdf: KDF.to_string(),
+                kdfparams,
+            },
+        };
+
+        serde_json::to_string_pretty(&keystore).map_err(RenegadeClientError::serde)
+    }
+
+    /// Decrypt the secrets from a JSON keystore with the given password
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<Self, RenegadeClientError> {
+        let keystore: AccountKeystore =
+            serde_json::from_str(keystore).map_err(RenegadeClientError::serde)?;
+        if keystore.version != KEYSTORE_VERSION {
+            let msg = format!("unsupported keystore version {}", keystore.version);
+            return Err(RenegadeClientError::keystore(msg));
+        }
+
+        let crypto = &keystore.crypto;
+        if crypto.cipher != CIPHER || crypto.kdf != KDF {
+            let msg = format!("unsupported cipher {} with kdf {}", crypto.cipher, crypto.kdf);
+            return Err(RenegadeClientError::keystore(msg));
+        }
+
+        let nonce = decode_hex("nonce", &crypto.nonce)?;
+        if nonce.len() != NONCE_BYTES {
+            let msg = format!("expected {NONCE_BYTES} byte nonce, got {}", nonce.len());
+            return Err(RenegadeClientError::keystore(msg));
+        }
+        let ciphertext = decode_hex("ciphertext", &crypto.ciphertext)?;
+
+        let cipher = build_cipher(password, &crypto.kdfparams)?;
+        let payload = Payload { msg: &ciphertext, aad: keystore.account_id.as_bytes() };
+        let bytes =
+            cipher.decrypt(Nonce::from_slice(&nonce), payload).map(Zeroizing::new).map_err(
+                |_| RenegadeClientError::keystore("incorrect password or corrupted keystore"),
+            )?;
+
+        Self::from_bytes(keystore.account_id, &bytes)
+    }
+
+    /// Encrypt the secrets & write them to a keystore file
+    ///
+    /// On Unix, a newly created file is readable only by its owner
+    pub fn save_keystore(
+        &self,
+        path: impl AsRef<Path>,
+        password: &str,
+    ) -> Result<(), RenegadeClientError> {
+        let keystore = self.to_keystore(password)?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, KEYSTORE_FILE_MODE);
+
+        options
+            .open(path.as_ref())
+            .and_then(|mut file| file.write_all(keystore.as_bytes()))
+            .map_err(|e| {
+                let msg = format!("failed to write {}: {e}", path.as_ref().display());
+                RenegadeClientError::keystore(msg)
+            })
+    }
+
+    /// Read & decrypt the secrets from a keystore file
+    pub fn load_keystore(
+        path: impl AsRef<Path>,
+        password: &str,
+    ) -> Result<Self, RenegadeClientError> {
+        let keystore = fs::read_to_string(path.as_ref()).map_err(|e| {
+            let msg = format!("failed to read {}: {e}", path.as_ref().display());
+            RenegadeClientError::keystore(msg)
+        })?;
+
+        Self::from_keystore(&keystore, password)
+    }
+
+    /// Serialize the secret values, excluding the account ID
+    fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let seed_bytes = Zeroizing::new(self.master_view_seed.to_bytes_be());
+        let schnorr_bytes = Zeroizing::new(self.schnorr_key.inner.into_bigint().to_bytes_be());
+
+        let mut bytes = Zeroizing::new(Vec::with_capacity(SECRETS_BYTES));
+        bytes.extend_from_slice(&*pad_scalar_bytes(&seed_bytes));
+        bytes.extend_from_slice(&*pad_scalar_bytes(&schnorr_bytes));
+        bytes.extend_from_slice(self.auth_hmac_key.inner());
+        bytes
+    }
+
+    /// Deserialize the secret values for the given account
+    fn from_bytes(account_id: Uuid, bytes: &[u8]) -> Result<Self, RenegadeClientError> {
+        if bytes.len() != SECRETS_BYTES {
+            let msg = format!("expected {SECRETS_BYTES} bytes of secrets, got {}", bytes.len());
+            return Err(RenegadeClientError::keystore(msg));
+        }
+
+        let (seed_bytes, rest) = bytes.split_at(SCALAR_BYTES);
+        let (schnorr_bytes, hmac_bytes) = rest.split_at(SCALAR_BYTES);
+
+        let master_view_seed = Scalar::from_be_bytes_mod_order(seed_bytes);
+        let inner = EmbeddedScalarField::from_be_bytes_mod_order(schnorr_bytes);
+        let auth_hmac_key =
+            HmacKey::from_bytes(hmac_bytes).map_err(RenegadeClientError::keystore)?;
+
+        Ok(Self {
+            account_id,
+            master_view_seed,
+            schnorr_key: SchnorrPrivateKey { inner },
+            auth_hmac_key,
+        })
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Stretch the password into an AES-256-GCM cipher
+fn build_cipher(password: &str, params: &ScryptParams) -> Result<Aes256Gcm, RenegadeClientError> {
+    if params.log_n > SCRYPT_LOG_N || params.r > SCRYPT_R || params.p > SCRYPT_P {
+        let msg = format!(
+            "scrypt params exceed the maximum: log_n = {}, r = {}, p = {}",
+            params.log_n, params.r, params.p,
+        );
+        return Err(RenegadeClientError::keystore(msg));
+    }
+
+    let salt = decode_hex("salt", &params.salt)?;
+    let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_BYTES)
+        .map_err(|e| RenegadeClientError::keystore(format!("invalid scrypt params: {e}")))?;
+
+    let mut key = Zeroizing::new([0u8; KEY_BYTES]);
+    scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, &mut *key)
+        .map_err(|e| RenegadeClientError::keystore(format!("failed to derive key: {e}")))?;
+
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&*key)))
+}
+
+/// Decode a hex-encoded keystore field
+fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>, RenegadeClientError> {
+    hex::decode(value).map_err(|e| RenegadeClientError::keystore(format!("invalid {field}: {e}")))
+}
+
+/// Left-pad the big-endian bytes of a field element to a fixed width
+fn pad_scalar_bytes(bytes: &[u8]) -> Zeroizing<[u8; SCALAR_BYTES]> {
+    let mut padded = Zeroizing::new([0u8; SCALAR_BYTES]);
+    padded[SCALAR_BYTES - bytes.len()..].copy_from_slice(bytes);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::signers::local::PrivateKeySigner;
+
+    use super::*;
+
+    /// A low scrypt cost, so that the tests run quickly
+    const TEST_LOG_N: u8 = 4;
+    /// The password used in the tests
+    const PASSWORD: &str = "correct horse battery staple";
+
+    /// Derive a fixed set of account secrets
+    async fn secrets() -> AccountSecrets {
+        let key = PrivateKeySigner::from_bytes(&[0x11; 32].into()).unwrap();
+        AccountSecrets::new(&key, 42161 /* chain_id */).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_keystore_round_trip() {
+        let secrets = secrets().await;
+        let keystore = secrets.to_keystore_with_cost(PASSWORD, TEST_LOG_N).unwrap();
+        let decrypted = AccountSecrets::from_keystore(&keystore, PASSWORD).unwrap();
+
+        assert_eq!(decrypted.account_id, secrets.account_id);
+        assert_eq!(decrypted.master_view_seed, secrets.master_view_seed);
+        assert_eq!(decrypted.schnorr_key.inner, secrets.schnorr_key.inner);
+        assert_eq!(decrypted.auth_hmac_key.inner(), secrets.auth_hmac_key.inner());
+    }
+
+    #[tokio::test]
+    async fn test_keystore_wrong_password() {
+        let keystore = secrets().await.to_keystore_with_cost(PASSWORD, TEST_LOG_N).unwrap();
+        let res = AccountSecrets::from_keystore(&keystore, "wrong password");
+
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_keystore_rejects_excessive_cost() {
+        let err = secrets().await.to_keystore_with_cost(PASSWORD, SCRYPT_LOG_N + 1).unwrap_err();
+        assert!(err.to_string().contains("scrypt params exceed the maximum"));
+    }
+}
@@ -4,11 +4,21 @@ use uuid::Uuid;
 
 use crate::http::RelayerHttpClientError;
 
+pub mod account_state;
 pub mod actions;
+pub mod algo;
 pub mod client;
 pub mod config;
 pub(crate) mod conversions;
+pub mod dead_mans_switch;
+pub mod expiry;
+pub mod keystore;
+pub mod pair;
+pub(crate) mod persistence;
+pub mod seed_manager;
+pub(crate) mod signing;
 pub(crate) mod utils;
+pub mod view_client;
 pub mod websocket;
 
 /// The error type for the renegade wallet client
@@ -17,6 +27,9 @@ pub enum RenegadeClientError {
     /// Custom error
     #[error("error: {0}")]
     Custom(String),
+    /// The client config is invalid or disagrees with the relayer
+    #[error("invalid client config: {0}")]
+    Config(String),
     /// An invalid order was provided
     #[error("invalid order: {0}")]
     InvalidOrder(String),
@@ -29,15 +42,27 @@ pub enum RenegadeClientError {
     /// Attempted to invoke admin API with a non-admin client
     #[error("not admin client")]
     NotAdmin,
+    /// The balance is insufficient for the requested action
+    #[error("insufficient balance: {0}")]
+    InsufficientBalance(String),
     /// A relayer error
     #[error("relayer error: {0}")]
     Relayer(RelayerHttpClientError),
+    /// An error querying the chain or submitting a transaction
+    #[error("rpc error: {0}")]
+    Rpc(String),
     /// A serde error
     #[error("serde error: {0}")]
     Serde(String),
     /// An error setting up the wallet
     #[error("failed to setup wallet: {0}")]
     Setup(String),
+    /// An error reading or writing an account secrets keystore
+    #[error("keystore error: {0}")]
+    Keystore(String),
+    /// An error persisting client-side state
+    #[error("persistence error: {0}")]
+    Persistence(String),
     /// A task error
     #[error("task error: task {task_id}: {message}")]
     Task {
@@ -61,6 +86,12 @@ impl RenegadeClientError {
         Self::Custom(msg.to_string())
     }
 
+    /// Create a new config error
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn config<T: ToString>(msg: T) -> Self {
+        Self::Config(msg.to_string())
+    }
+
     /// Create a new invalid order error
     #[allow(clippy::needless_pass_by_value)]
     pub fn invalid_order<T: ToString>(msg: T) -> Self {
@@ -79,18 +110,42 @@ impl RenegadeClientError {
         Self::Signing(msg.to_string())
     }
 
+    /// Create a new insufficient balance error
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn insufficient_balance<T: ToString>(msg: T) -> Self {
+        Self::InsufficientBalance(msg.to_string())
+    }
+
+    /// Create a new RPC error
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn rpc<T: ToString>(msg: T) -> Self {
+        Self::Rpc(msg.to_string())
+    }
+
     /// Create a new setup error
     #[allow(clippy::needless_pass_by_value)]
     pub fn setup<T: ToString>(msg: T) -> Self {
         Self::Setup(msg.to_string())
     }
 
+    /// Create a new keystore error
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn keystore<T: ToString>(msg: T) -> Self {
+        Self::Keystore(msg.to_string())
+    }
+
     /// Create a new serde error
     #[allow(clippy::needless_pass_by_value)]
     pub fn serde<T: ToString>(msg: T) -> Self {
         Self::Serde(msg.to_string())
     }
 
+    /// Create a new persistence error
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn persistence<T: ToString>(msg: T) -> Self {
+        Self::Persistence(msg.to_string())
+    }
+
     /// Create a new task error
     #[allow(clippy::needless_pass_by_value)]
     pub fn task<T: ToString>(task_id: Uuid, msg: T) -> Self {
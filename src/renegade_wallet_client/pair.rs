@@ -0,0 +1,243 @@
+//! Trading pairs, for describing orders by side, size & limit price
+//!
+//! An order's intent is expressed as an input amount & a minimum price in
+//! output atoms per input atom. Traders usually think instead in terms of a
+//! base size & a limit price in quote units per base unit, e.g. "buy 2 WETH at
+//! at most 3000 USDC". A [`TradingPair`] holds the token decimals needed to
+//! convert the latter into the former.
+
+use alloy::primitives::{Address, U256};
+use renegade_circuit_types::Amount;
+
+use crate::RenegadeClientError;
+
+// -------------
+// | Constants |
+// -------------
+
+/// The number of decimals with which a limit price is converted to an integer
+const PRICE_DECIMALS: u32 = 18;
+/// The maximum number of decimals a token may have
+const MAX_TOKEN_DECIMALS: u8 = 36;
+
+// ---------
+// | Types |
+// ---------
+
+/// A token & the number of decimals in its units
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Token {
+    /// The token's mint address
+    pub address: Address,
+    /// The number of decimals in one unit of the token
+    pub decimals: u8,
+}
+
+impl Token {
+    /// Create a new token
+    pub fn new(address: Address, decimals: u8) -> Self {
+        Self { address, decimals }
+    }
+}
+
+/// A pair of tokens, priced in quote units per base unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TradingPair {
+    /// The base token, in which order sizes are given
+    pub base: Token,
+    /// The quote token, in which prices are given
+    pub quote: Token,
+}
+
+/// The side of a pair an order is on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// Buy the base token with the quote token
+    Buy,
+    /// Sell the base token for the quote token
+    Sell,
+}
+
+/// The intent parameters of a limit order
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LimitOrderParams {
+    /// The input token mint address
+    pub input_mint: Address,
+    /// The output token mint address
+    pub output_mint: Address,
+    /// The amount of the input token to trade
+    pub amount_in: Amount,
+    /// The minimum output token amount that must be received
+    pub min_output_amount: Amount,
+}
+
+impl TradingPair {
+    /// Create a new trading pair
+    pub fn new(base: Token, quote: Token) -> Self {
+        Self { base, quote }
+    }
+
+    /// Convert a base amount to a quote amount at the given price
+    ///
+    /// # Arguments
+    /// * `base_amount` - The base amount, in atoms of the base token
+    /// * `price` - The price, in quote units per base unit
+    /// * `round_up` - Whether to round the quote amount up rather than down
+    pub fn quote_amount(
+        &self,
+        base_amount: Amount,
+        price: f64,
+        round_up: bool,
+    ) -> Result<Amount, RenegadeClientError> {
+        self.validate_decimals()?;
+        if !price.is_finite() || price <= 0. {
+            let msg = format!("limit price must be positive, got {price}");
+            return Err(RenegadeClientError::invalid_order(msg));
+        }
+
+        // Scale the price to an integer, rounding in the same direction as the
+        // quote amount
+        let scaled = price * 10f64.powi(PRICE_DECIMALS as i32);
+        let scaled = if round_up { scaled.ceil() } else { scaled.floor() };
+        if scaled >= u128::MAX as f64 {
+            return Err(RenegadeClientError::invalid_order(format!(
+                "limit price {price} too large"
+            )));
+        }
+        let scaled_price = U256::from(scaled as u128);
+
+        // quote = base * price * 10^quote_decimals / 10^base_decimals
+        let numerator = U256::from(base_amount)
+            .checked_mul(scaled_price)
+            .and_then(|n| n.checked_mul(pow10(u32::from(self.quote.decimals))))
+            .ok_or_else(|| RenegadeClientError::invalid_order("order size overflows"))?;
+        let denominator = pow10(u32::from(self.base.decimals) + PRICE_DECIMALS);
+        let quote =
+            if round_up { numerator.div_ceil(denominator) } else { numerator / denominator };
+
+        Amount::try_from(quote).map_err(|_| {
+            RenegadeClientError::invalid_order(format!("quote amount {quote} too large"))
+        })
+    }
+
+    /// Compute the intent parameters of a limit order
+    ///
+    /// Amounts are rounded so that the order never executes at a price worse
+    /// than the limit: a sell receives at least the limit price, & a buy
+    /// spends at most the limit price.
+    pub(crate) fn limit_order_params(
+        &self,
+        side: Side,
+        base_size: Amount,
+        limit_price: f64,
+    ) -> Result<LimitOrderParams, RenegadeClientError> {
+        if base_size == 0 {
+            return Err(RenegadeClientError::invalid_order("base size must be non-zero"));
+        }
+
+        let params = match side {
+            Side::Sell => LimitOrderParams {
+                input_mint: self.base.address,
+                output_mint: self.quote.address,
+                amount_in: base_size,
+                min_output_amount: self.quote_amount(base_size, limit_price, true)?,
+            },
+            Side::Buy => LimitOrderParams {
+                input_mint: self.quote.address,
+                output_mint: self.base.address,
+                amount_in: self.quote_amount(base_size, limit_price, false)?,
+                min_output_amount: base_size,
+            },
+        };
+
+        if params.amount_in == 0 {
+            let msg = format!("limit price {limit_price} rounds the order's input amount to zero");
+            return Err(RenegadeClientError::invalid_order(msg));
+        }
+
+        Ok(params)
+    }
+
+    /// Check that both tokens' decimals are within range
+    fn validate_decimals(&self) -> Result<(), RenegadeClientError> {
+        for token in [self.base, self.quote] {
+            if token.decimals > MAX_TOKEN_DECIMALS {
+                let msg = format!("{:#x} has too many decimals: {}", token.address, token.decimals);
+                return Err(RenegadeClientError::invalid_order(msg));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Compute ten to the given power
+fn pow10(exp: u32) -> U256 {
+    U256::from(10u64).pow(U256::from(exp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An awkward limit price, so that amounts must be rounded
+    const LIMIT_PRICE: f64 = 3000.123456789;
+
+    /// A pair with an 18 decimal base token & a 6 decimal quote token
+    fn pair() -> TradingPair {
+        let base = Token::new(Address::repeat_byte(0x01), 18);
+        let quote = Token::new(Address::repeat_byte(0x02), 6);
+        TradingPair::new(base, quote)
+    }
+
+    /// The price of a trade, in quote units per base unit
+    fn trade_price(pair: &TradingPair, base_amount: Amount, quote_amount: Amount) -> f64 {
+        let base = base_amount as f64 / 10f64.powi(i32::from(pair.base.decimals));
+        let quote = quote_amount as f64 / 10f64.powi(i32::from(pair.quote.decimals));
+        quote / base
+    }
+
+    #[test]
+    fn test_sell_receives_at_least_limit_price() {
+        let pair = pair();
+        let base_size = 2 * 10u128.pow(18) + 7;
+        let params = pair.limit_order_params(Side::Sell, base_size, LIMIT_PRICE).unwrap();
+
+        assert_eq!(params.input_mint, pair.base.address);
+        assert_eq!(params.output_mint, pair.quote.address);
+        assert_eq!(params.amount_in, base_size);
+        assert_eq!(params.min_output_amount, 6_000_246_914);
+        assert!(trade_price(&pair, params.amount_in, params.min_output_amount) >= LIMIT_PRICE);
+    }
+
+    #[test]
+    fn test_buy_spends_at_most_limit_price() {
+        let pair = pair();
+        let base_size = 2 * 10u128.pow(18) + 7;
+        let params = pair.limit_order_params(Side::Buy, base_size, LIMIT_PRICE).unwrap();
+
+        assert_eq!(params.input_mint, pair.quote.address);
+        assert_eq!(params.output_mint, pair.base.address);
+        assert_eq!(params.amount_in, 6_000_246_913);
+        assert_eq!(params.min_output_amount, base_size);
+        assert!(trade_price(&pair, params.min_output_amount, params.amount_in) <= LIMIT_PRICE);
+    }
+
+    #[test]
+    fn test_zero_input_amount_rejected() {
+        // One atom of the base token is worth less than one atom of the quote
+        // token, so a buy would spend nothing
+        let err = pair().limit_order_params(Side::Buy, 1, LIMIT_PRICE).unwrap_err();
+        assert!(err.to_string().contains("rounds the order's input amount to zero"));
+    }
+
+    #[test]
+    fn test_order_size_overflow_rejected() {
+        let err = pair().limit_order_params(Side::Sell, Amount::MAX, 1e20).unwrap_err();
+        assert!(err.to_string().contains("order size overflows"));
+    }
+}
@@ -0,0 +1,107 @@
+//! Helpers for persisting client-side state as JSON files
+//!
+//! Client-side engines persist one record per file in a directory, named by
+//! the record's ID. Records are written to a temporary file & renamed into
+//! place, so that a crash mid-write never leaves a truncated record.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use uuid::Uuid;
+
+use crate::RenegadeClientError;
+
+/// The extension of a persisted record
+const RECORD_EXTENSION: &str = "json";
+/// The extension of a record being written
+const TMP_EXTENSION: &str = "json.tmp";
+
+/// A directory of JSON records keyed by ID
+#[derive(Clone, Debug)]
+pub(crate) struct JsonDir {
+    /// The directory holding the records
+    dir: PathBuf,
+}
+
+impl JsonDir {
+    /// Open a directory of records, creating it if it does not exist
+    pub(crate) fn open(dir: impl AsRef<Path>) -> Result<Self, RenegadeClientError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).map_err(|e| io_error("create", &dir, e))?;
+        Ok(Self { dir })
+    }
+
+    /// Write a record, replacing any existing record with the same ID
+    pub(crate) fn save<T: Serialize>(
+        &self,
+        id: Uuid,
+        record: &T,
+    ) -> Result<(), RenegadeClientError> {
+        let json = serde_json::to_vec_pretty(record).map_err(RenegadeClientError::serde)?;
+        let tmp = self.dir.join(format!("{id}.{TMP_EXTENSION}"));
+        let path = self.record_path(id);
+
+        fs::write(&tmp, json).map_err(|e| io_error("write", &tmp, e))?;
+        fs::rename(&tmp, &path).map_err(|e| io_error("write", &path, e))
+    }
+
+    /// Read a record, if one exists with the given ID
+    pub(crate) fn load<T: DeserializeOwned>(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<T>, RenegadeClientError> {
+        let path = self.record_path(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        read_record(&path).map(Some)
+    }
+
+    /// Read all records in the directory
+    pub(crate) fn list<T: DeserializeOwned>(&self) -> Result<Vec<T>, RenegadeClientError> {
+        let entries = fs::read_dir(&self.dir).map_err(|e| io_error("read", &self.dir, e))?;
+
+        let mut records = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|e| io_error("read", &self.dir, e))?.path();
+            if path.extension().is_some_and(|ext| ext == RECORD_EXTENSION) {
+                records.push(read_record(&path)?);
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Delete a record, if one exists with the given ID
+    pub(crate) fn remove(&self, id: Uuid) -> Result<(), RenegadeClientError> {
+        let path = self.record_path(id);
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error("remove", &path, e)),
+            _ => Ok(()),
+        }
+    }
+
+    /// The path of the record with the given ID
+    fn record_path(&self, id: Uuid) -> PathBuf {
+        self.dir.join(format!("{id}.{RECORD_EXTENSION}"))
+    }
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Read & deserialize a record file
+fn read_record<T: DeserializeOwned>(path: &Path) -> Result<T, RenegadeClientError> {
+    let json = fs::read(path).map_err(|e| io_error("read", path, e))?;
+    serde_json::from_slice(&json).map_err(RenegadeClientError::serde)
+}
+
+/// Build a persistence error for a failed filesystem operation
+fn io_error(op: &str, path: &Path, err: std::io::Error) -> RenegadeClientError {
+    RenegadeClientError::persistence(format!("failed to {op} {}: {err}", path.display()))
+}
@@ -0,0 +1,148 @@
+//! A local cache of the account's seed CSPRNGs
+//!
+//! New state objects are created with recovery & share stream seeds sampled
+//! from two CSPRNGs that the relayer tracks for the account. Rather than
+//! fetching both CSPRNGs before every order placement or deposit, the manager
+//! loads them once & advances them locally.
+//!
+//! Actions that consume seeds hold the manager's lock until the relayer has
+//! accepted them, so that concurrent actions draw distinct seeds in the order
+//! the relayer applies them. If the relayer rejects an action, the CSPRNGs are
+//! resynced, and the action is retried once if the local CSPRNGs were stale,
+//! e.g. because the relayer drew seeds of its own to settle a fill.
+
+use alloy::signers::Signer;
+use renegade_constants::Scalar;
+use renegade_darkpool_types::csprng::PoseidonCSPRNG;
+use tokio::sync::Mutex;
+
+use crate::{RenegadeClientError, client::RenegadeClient};
+
+// ----------------
+// | Seed Manager |
+// ----------------
+
+/// A mutex-guarded local copy of the account's seed CSPRNGs
+///
+/// Shared between clones of a client, so that all of them draw from the same
+/// CSPRNG state
+#[derive(Default)]
+pub struct SeedManager {
+    /// The cached CSPRNGs, or `None` if they must be loaded from the relayer
+    seeds: Mutex<Option<AccountSeeds>>,
+}
+
+impl SeedManager {
+    /// Create a new seed manager, which loads the CSPRNGs on first use
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop the cached CSPRNGs, so that they are reloaded from the relayer
+    /// when next used
+    pub async fn invalidate(&self) {
+        *self.seeds.lock().await = None;
+    }
+}
+
+/// The account's seed CSPRNGs
+#[derive(Clone)]
+pub(crate) struct AccountSeeds {
+    /// The CSPRNG for recovery stream seeds
+    recovery_seed_csprng: PoseidonCSPRNG,
+    /// The CSPRNG for share stream seeds
+    share_seed_csprng: PoseidonCSPRNG,
+}
+
+/// The seeds of the streams used to create a new state object
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct StreamSeeds {
+    /// The recovery stream seed
+    pub recovery_stream_seed: Scalar,
+    /// The share stream seed
+    pub share_stream_seed: Scalar,
+}
+
+impl AccountSeeds {
+    /// Draw the seeds for the next state object
+    pub(crate) fn next_stream_seeds(&mut self) -> StreamSeeds {
+        // The CSPRNGs are infinite streams
+        let recovery_stream_seed = self.recovery_seed_csprng.next().unwrap();
+        let share_stream_seed = self.share_seed_csprng.next().unwrap();
+        StreamSeeds { recovery_stream_seed, share_stream_seed }
+    }
+
+    /// Get the seeds the next state object would use, without advancing the
+    /// CSPRNGs
+    fn peek_stream_seeds(&self) -> StreamSeeds {
+        self.clone().next_stream_seeds()
+    }
+}
+
+// ----------------------
+// | Client Integration |
+// ----------------------
+
+impl<S: Signer> RenegadeClient<S> {
+    /// Resync the client's seed CSPRNGs with the relayer
+    ///
+    /// This is done automatically when the relayer rejects an action, but may
+    /// be called after seeds are consumed outside the client
+    pub async fn resync_account_seeds(&self) -> Result<(), RenegadeClientError> {
+        let mut cached = self.seed_manager.seeds.lock().await;
+        *cached = Some(self.fetch_account_seeds().await?);
+        Ok(())
+    }
+
+    /// Run an action that draws from the account's seed CSPRNGs
+    ///
+    /// The action should draw its seeds, then submit its request to the
+    /// relayer. The CSPRNGs are locked for the duration of the action, and
+    /// only advanced if the action succeeds, so actions should submit
+    /// non-blocking & wait for the relayer task after this returns.
+    pub(crate) async fn with_account_seeds<T>(
+        &self,
+        mut action: impl AsyncFnMut(&mut AccountSeeds) -> Result<T, RenegadeClientError>,
+    ) -> Result<T, RenegadeClientError> {
+        let mut cached = self.seed_manager.seeds.lock().await;
+        let seeds = match cached.take() {
+            Some(seeds) => seeds,
+            None => self.fetch_account_seeds().await?,
+        };
+
+        let mut drawn = seeds.clone();
+        let err = match action(&mut drawn).await {
+            Ok(res) => {
+                *cached = Some(drawn);
+                return Ok(res);
+            },
+            Err(e) => e,
+        };
+
+        // The action failed, so resync the CSPRNGs. If they were up to date, the
+        // failure was unrelated to the seeds. If the relayer has advanced past the
+        // drawn seeds, it applied the action despite the error, so it must not be
+        // retried
+        let fresh = self.fetch_account_seeds().await?;
+        let next_seeds = fresh.peek_stream_seeds();
+        if next_seeds == seeds.peek_stream_seeds() || next_seeds == drawn.peek_stream_seeds() {
+            *cached = Some(fresh);
+            return Err(err);
+        }
+
+        // Otherwise, retry with the relayer's CSPRNGs. If the retry fails, the
+        // CSPRNGs are reloaded on next use
+        let mut drawn = fresh;
+        let res = action(&mut drawn).await;
+        if res.is_ok() {
+            *cached = Some(drawn);
+        }
+        res
+    }
+
+    /// Fetch the account's seed CSPRNGs from the relayer
+    async fn fetch_account_seeds(&self) -> Result<AccountSeeds, RenegadeClientError> {
+        let (recovery_seed_csprng, share_seed_csprng) = self.get_account_seeds().await?;
+        Ok(AccountSeeds { recovery_seed_csprng, share_seed_csprng })
+    }
+}
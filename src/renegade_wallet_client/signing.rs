@@ -0,0 +1,304 @@
+//! ECDSA signing helpers for account actions
+//!
+//! These mirror the digests verified by the darkpool contracts, but sign them
+//! with an async [`Signer`] so that the account key may live outside the
+//! process, e.g. in a KMS, HSM, or remote signer.
+
+use alloy::{
+    primitives::{Address, B256, U256, keccak256},
+    signers::{Signature, Signer},
+    sol,
+    sol_types::{SolStruct, SolValue, eip712_domain},
+};
+use renegade_solidity_abi::v2::IDarkpoolV2::{
+    self, PublicIntentPermit, SignatureWithNonce, WithdrawalAuth,
+};
+
+use crate::RenegadeClientError;
+
+// -------------
+// | Constants |
+// -------------
+
+/// The EIP-712 domain name of the Permit2 contract
+const PERMIT2_DOMAIN_NAME: &str = "Permit2";
+
+// ---------------------
+// | Permit2 ABI Types |
+// ---------------------
+
+sol! {
+    /// The token and amount a Permit2 signature authorizes
+    struct TokenPermissions {
+        address token;
+        uint256 amount;
+    }
+
+    /// The witness binding a Permit2 transfer to a deposit
+    struct DepositWitness {
+        uint256 depositCommitment;
+    }
+
+    /// A Permit2 signature-based transfer with a deposit witness
+    struct PermitWitnessTransferFrom {
+        TokenPermissions permitted;
+        address spender;
+        uint256 nonce;
+        uint256 deadline;
+        DepositWitness witness;
+    }
+}
+
+// -----------
+// | Signing |
+// -----------
+
+/// Sign a payload, binding the signature to a fresh nonce & the chain ID
+///
+/// The signed digest is `H(H(payload) || nonce || chainId)`
+pub(crate) async fn sign_with_nonce<S: Signer + ?Sized>(
+    payload: &[u8],
+    chain_id: u64,
+    signer: &S,
+) -> Result<SignatureWithNonce, RenegadeClientError> {
+    sign_with_given_nonce(payload, random_nonce(), chain_id, signer).await
+}
+
+/// Sign a payload, binding the signature to the given nonce & the chain ID
+async fn sign_with_given_nonce<S: Signer + ?Sized>(
+    payload: &[u8],
+    nonce: U256,
+    chain_id: u64,
+    signer: &S,
+) -> Result<SignatureWithNonce, RenegadeClientError> {
+    let digest = keccak256(
+        [keccak256(payload).as_slice(), &nonce.to_be_bytes::<32>(), &chain_id_bytes(chain_id)]
+            .concat(),
+    );
+
+    let signature = sign_digest(digest, signer).await?;
+    Ok(SignatureWithNonce { nonce, signature: signature.as_bytes().to_vec().into() })
+}
+
+/// Sign a public intent permit
+///
+/// The payload is the ABI encoding of the permit, matching the intent hash
+/// used to compute the intent's nullifier
+pub(crate) async fn sign_public_intent_permit<S: Signer + ?Sized>(
+    permit: &PublicIntentPermit,
+    chain_id: u64,
+    signer: &S,
+) -> Result<SignatureWithNonce, RenegadeClientError> {
+    sign_with_nonce(&permit.abi_encode(), chain_id, signer).await
+}
+
+/// Sign a commitment to the post-withdrawal balance, authorizing a withdrawal
+///
+/// The signed digest is `H(commitment || chainId)`
+pub(crate) async fn sign_withdrawal_auth<S: Signer + ?Sized>(
+    commitment: U256,
+    chain_id: u64,
+    signer: &S,
+) -> Result<WithdrawalAuth, RenegadeClientError> {
+    let digest = keccak256([commitment.to_be_bytes::<32>(), chain_id_bytes(chain_id)].concat());
+    let signature = sign_digest(digest, signer).await?;
+
+    Ok(WithdrawalAuth { signature: signature.as_bytes().to_vec().into() })
+}
+
+/// Sign a Permit2 transfer authorizing the darkpool to pull a deposit
+///
+/// The permit's witness commits to the post-deposit balance, and its nonce is
+/// sampled at random. Permit2 nonces are single use, so the permit is given no
+/// deadline.
+pub(crate) async fn sign_deposit_permit<S: Signer + ?Sized>(
+    commitment: U256,
+    deposit: &IDarkpoolV2::Deposit,
+    chain_id: u64,
+    darkpool_address: Address,
+    permit2_address: Address,
+    signer: &S,
+) -> Result<(PermitWitnessTransferFrom, Signature), RenegadeClientError> {
+    let nonce = random_nonce();
+    sign_deposit_permit_with_nonce(
+        commitment,
+        deposit,
+        nonce,
+        chain_id,
+        darkpool_address,
+        permit2_address,
+        signer,
+    )
+    .await
+}
+
+/// Sign a Permit2 deposit transfer with the given nonce
+async fn sign_deposit_permit_with_nonce<S: Signer + ?Sized>(
+    commitment: U256,
+    deposit: &IDarkpoolV2::Deposit,
+    nonce: U256,
+    chain_id: u64,
+    darkpool_address: Address,
+    permit2_address: Address,
+    signer: &S,
+) -> Result<(PermitWitnessTransferFrom, Signature), RenegadeClientError> {
+    let permit = PermitWitnessTransferFrom {
+        permitted: TokenPermissions { token: deposit.token, amount: deposit.amount },
+        spender: darkpool_address,
+        nonce,
+        deadline: U256::MAX,
+        witness: DepositWitness { depositCommitment: commitment },
+    };
+
+    let domain = eip712_domain! {
+        name: PERMIT2_DOMAIN_NAME,
+        chain_id: chain_id,
+        verifying_contract: permit2_address,
+    };
+    let digest = permit.eip712_signing_hash(&domain);
+    let signature = sign_digest(digest, signer).await?;
+
+    Ok((permit, signature))
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Sign a digest with the given signer
+async fn sign_digest<S: Signer + ?Sized>(
+    digest: B256,
+    signer: &S,
+) -> Result<Signature, RenegadeClientError> {
+    signer.sign_hash(&digest).await.map_err(RenegadeClientError::signing)
+}
+
+/// Sample a random nonce
+fn random_nonce() -> U256 {
+    U256::from_be_bytes(rand::random::<[u8; 32]>())
+}
+
+/// Encode a chain ID as a big-endian `uint256`
+fn chain_id_bytes(chain_id: u64) -> [u8; 32] {
+    U256::from(chain_id).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    //! Checks that the async signing paths produce the same signatures as the
+    //! synchronous helpers in the darkpool's ABI crate. ECDSA signatures are
+    //! deterministic (RFC 6979), so equal digests give equal bytes.
+
+    use alloy::signers::local::PrivateKeySigner;
+    use renegade_external_api::types::{ApiIntent, ApiOrderCore, OrderType};
+    use renegade_solidity_abi::v2::transfer_auth::{
+        deposit::create_deposit_permit, withdrawal::create_withdrawal_auth,
+    };
+    use uuid::Uuid;
+
+    use crate::actions::place_order::compute_min_price;
+
+    use super::*;
+
+    /// The chain ID used in the tests
+    const CHAIN_ID: u64 = 42161;
+
+    /// A fixed signer for the tests
+    fn signer() -> PrivateKeySigner {
+        PrivateKeySigner::from_bytes(&B256::repeat_byte(0x11)).unwrap()
+    }
+
+    /// A fixed commitment for the tests
+    fn commitment() -> U256 {
+        U256::from_be_bytes([0x22; 32])
+    }
+
+    #[tokio::test]
+    async fn test_sign_with_nonce_matches_upstream() {
+        let signer = signer();
+        let payload = commitment().to_be_bytes::<32>();
+
+        let expected = SignatureWithNonce::sign(&payload, CHAIN_ID, &signer).unwrap();
+        let actual =
+            sign_with_given_nonce(&payload, expected.nonce, CHAIN_ID, &signer).await.unwrap();
+
+        assert_eq!(actual.nonce, expected.nonce);
+        assert_eq!(actual.signature, expected.signature);
+    }
+
+    #[tokio::test]
+    async fn test_sign_public_intent_permit_matches_upstream() {
+        let signer = signer();
+        let order = ApiOrderCore {
+            id: Uuid::new_v4(),
+            intent: ApiIntent {
+                in_token: Address::repeat_byte(0x01),
+                out_token: Address::repeat_byte(0x02),
+                owner: signer.address(),
+                amount_in: 1_000_000,
+                min_price: compute_min_price(2_000_000, 1_000_000),
+            },
+            min_fill_size: 0,
+            order_type: OrderType::PublicOrder,
+            allow_external_matches: true,
+        };
+        let permit = PublicIntentPermit {
+            intent: order.get_intent().into(),
+            executor: Address::repeat_byte(0x03),
+        };
+
+        let expected = permit.sign(CHAIN_ID, &signer).unwrap();
+        let actual = sign_with_given_nonce(&permit.abi_encode(), expected.nonce, CHAIN_ID, &signer)
+            .await
+            .unwrap();
+
+        assert_eq!(actual.nonce, expected.nonce);
+        assert_eq!(actual.signature, expected.signature);
+    }
+
+    #[tokio::test]
+    async fn test_sign_withdrawal_auth_matches_upstream() {
+        let signer = signer();
+
+        let expected = create_withdrawal_auth(commitment(), CHAIN_ID, &signer).unwrap();
+        let actual = sign_withdrawal_auth(commitment(), CHAIN_ID, &signer).await.unwrap();
+
+        assert_eq!(actual.signature, expected.signature);
+    }
+
+    #[tokio::test]
+    async fn test_sign_deposit_permit_matches_upstream() {
+        let signer = signer();
+        let darkpool = Address::repeat_byte(0x04);
+        let permit2 = Address::repeat_byte(0x05);
+        let deposit = IDarkpoolV2::Deposit {
+            from: signer.address(),
+            token: Address::repeat_byte(0x01),
+            amount: U256::from(1_000_000u64),
+        };
+
+        let (expected_permit, expected_sig) = create_deposit_permit(
+            commitment(),
+            deposit.clone(),
+            CHAIN_ID,
+            darkpool,
+            permit2,
+            &signer,
+        )
+        .unwrap();
+        let (permit, sig) = sign_deposit_permit_with_nonce(
+            commitment(),
+            &deposit,
+            expected_permit.nonce,
+            CHAIN_ID,
+            darkpool,
+            permit2,
+            &signer,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(permit.deadline, expected_permit.deadline);
+        assert_eq!(sig.as_bytes(), expected_sig.as_bytes());
+    }
+}
@@ -1,12 +1,16 @@
 //! Shared utilities for the Renegade wallet client
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use crate::auth::HmacKey;
-use alloy::{primitives::keccak256, signers::SignerSync, signers::local::PrivateKeySigner, sol};
+use alloy::{primitives::keccak256, signers::Signer, sol};
 use ark_ff::PrimeField;
 use renegade_circuit_types::schnorr::SchnorrPrivateKey;
 use renegade_constants::{EmbeddedScalarField, Scalar};
 use uuid::Uuid;
 
+use crate::RenegadeClientError;
+
 // -------------
 // | Constants |
 // -------------
@@ -20,6 +24,9 @@ const SCHNORR_KEY_MESSAGE_PREFIX: &[u8] = b"schnorr key";
 /// The message prefix used to derive the auth HMAC key
 const AUTH_HMAC_KEY_MESSAGE_PREFIX: &[u8] = b"auth hmac key";
 
+/// The index of the account derived when none is specified
+pub const DEFAULT_ACCOUNT_INDEX: u32 = 0;
+
 /// The number of bytes from a keccak hash
 const KECCAK_HASH_BYTES: usize = 32;
 /// The number of bytes we extend into to get a scalar
@@ -36,10 +43,25 @@ sol! {
     #[sol(rpc)]
     function approve(address spender, uint256 amount) external returns (bool);
 
+    // ERC20 balance and allowance getters
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address owner) external view returns (uint256);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+
+    // Wrapped ether interface
+    #[sol(rpc)]
+    interface IWETH {
+        function deposit() external payable;
+        function withdraw(uint256 wad) external;
+    }
+
     // Permit2 AllowanceTransfer interface
     #[sol(rpc)]
     interface IAllowanceTransfer {
         function approve(address token, address spender, uint160 amount, uint48 expiration) external;
+        function allowance(address user, address token, address spender) external view returns (uint160 amount, uint48 expiration, uint48 nonce);
     }
 }
 
@@ -65,67 +87,72 @@ pub(crate) use unwrap_field;
 // | Helpers |
 // -----------
 
-/// Derive an account ID from a signing key & chain ID
-pub fn derive_account_id(key: &PrivateKeySigner, chain_id: u64) -> Result<Uuid, String> {
-    let sig_bytes = get_sig_bytes(&account_id_message(chain_id), key)?;
+/// Derive an account ID from a signing key, chain ID & account index
+pub async fn derive_account_id<S: Signer + ?Sized>(
+    key: &S,
+    chain_id: u64,
+    account_index: u32,
+) -> Result<Uuid, String> {
+    let message = derivation_message(ACCOUNT_ID_MESSAGE_PREFIX, chain_id, account_index);
+    let sig_bytes = get_sig_bytes(&message, key).await?;
     let account_id = Uuid::from_slice(&sig_bytes[..ACCOUNT_ID_BYTES])
         .map_err(|e| format!("failed to derive account ID: {e}"))?;
     Ok(account_id)
 }
 
-/// Derive a master view seed from a signing key & chain ID
-pub fn derive_master_view_seed(key: &PrivateKeySigner, chain_id: u64) -> Result<Scalar, String> {
-    let sig_bytes = get_extended_sig_bytes(&master_view_seed_message(chain_id), key)?;
+/// Derive a master view seed from a signing key, chain ID & account index
+pub async fn derive_master_view_seed<S: Signer + ?Sized>(
+    key: &S,
+    chain_id: u64,
+    account_index: u32,
+) -> Result<Scalar, String> {
+    let message = derivation_message(MASTER_VIEW_SEED_MESSAGE_PREFIX, chain_id, account_index);
+    let sig_bytes = get_extended_sig_bytes(&message, key).await?;
     Ok(Scalar::from_be_bytes_mod_order(&sig_bytes))
 }
 
-/// Derive a Schnorr private key from a signing key & chain ID
-pub fn derive_schnorr_key(
-    key: &PrivateKeySigner,
+/// Derive a Schnorr private key from a signing key, chain ID & account index
+pub async fn derive_schnorr_key<S: Signer + ?Sized>(
+    key: &S,
     chain_id: u64,
+    account_index: u32,
 ) -> Result<SchnorrPrivateKey, String> {
-    let sig_bytes = get_extended_sig_bytes(&schnorr_key_message(chain_id), key)?;
+    let message = derivation_message(SCHNORR_KEY_MESSAGE_PREFIX, chain_id, account_index);
+    let sig_bytes = get_extended_sig_bytes(&message, key).await?;
     let inner = EmbeddedScalarField::from_be_bytes_mod_order(&sig_bytes);
     Ok(SchnorrPrivateKey { inner })
 }
 
-/// Derive an HMAC key for API auth from a signing key & chain ID
-pub fn derive_auth_hmac_key(key: &PrivateKeySigner, chain_id: u64) -> Result<HmacKey, String> {
-    get_sig_bytes(&auth_hmac_key_message(chain_id), key).map(HmacKey)
-}
-
-/// Generate the message to sign for account ID derivation
-fn account_id_message(chain_id: u64) -> Vec<u8> {
-    let mut message = Vec::from(ACCOUNT_ID_MESSAGE_PREFIX);
-    message.extend_from_slice(&chain_id.to_be_bytes());
-    message
-}
-
-/// Generate the message to sign for master view seed derivation
-fn master_view_seed_message(chain_id: u64) -> Vec<u8> {
-    let mut message = Vec::from(MASTER_VIEW_SEED_MESSAGE_PREFIX);
-    message.extend_from_slice(&chain_id.to_be_bytes());
-    message
-}
-
-/// Generate the message to sign for Schnorr key derivation
-fn schnorr_key_message(chain_id: u64) -> Vec<u8> {
-    let mut message = Vec::from(SCHNORR_KEY_MESSAGE_PREFIX);
-    message.extend_from_slice(&chain_id.to_be_bytes());
-    message
+/// Derive an HMAC key for API auth from a signing key, chain ID & account index
+pub async fn derive_auth_hmac_key<S: Signer + ?Sized>(
+    key: &S,
+    chain_id: u64,
+    account_index: u32,
+) -> Result<HmacKey, String> {
+    let message = derivation_message(AUTH_HMAC_KEY_MESSAGE_PREFIX, chain_id, account_index);
+    get_sig_bytes(&message, key).await.map(HmacKey)
 }
 
-/// Generate the message to sign for auth HMAC key derivation
-fn auth_hmac_key_message(chain_id: u64) -> Vec<u8> {
-    let mut message = Vec::from(AUTH_HMAC_KEY_MESSAGE_PREFIX);
+/// Generate the message to sign for a derivation
+///
+/// The account index is only appended when non-zero, so that the default
+/// account's messages are unchanged from before indices were introduced
+fn derivation_message(prefix: &[u8], chain_id: u64, account_index: u32) -> Vec<u8> {
+    let mut message = Vec::from(prefix);
     message.extend_from_slice(&chain_id.to_be_bytes());
+    if account_index != DEFAULT_ACCOUNT_INDEX {
+        message.extend_from_slice(&account_index.to_be_bytes());
+    }
     message
 }
 
 /// Sign a message, serialize the signature into bytes
-fn get_sig_bytes(msg: &[u8], key: &PrivateKeySigner) -> Result<[u8; KECCAK_HASH_BYTES], String> {
+async fn get_sig_bytes<S: Signer + ?Sized>(
+    msg: &[u8],
+    key: &S,
+) -> Result<[u8; KECCAK_HASH_BYTES], String> {
     let digest = keccak256(msg);
-    let sig = key.sign_hash_sync(&digest).map_err(|e| format!("failed to sign message: {e}"))?;
+    let sig = key.sign_hash(&digest).await.map_err(|e| format!("failed to sign message: {e}"))?;
 
     // Take the keccak hash of the signature to disperse its elements
     let bytes: Vec<u8> = sig.into();
@@ -134,11 +161,11 @@ fn get_sig_bytes(msg: &[u8], key: &PrivateKeySigner) -> Result<[u8; KECCAK_HASH_
 
 /// Sign a message, serialize the signature into bytes, and extend the bytes to
 /// support secure reduction into a field
-fn get_extended_sig_bytes(
+async fn get_extended_sig_bytes<S: Signer + ?Sized>(
     msg: &[u8],
-    key: &PrivateKeySigner,
+    key: &S,
 ) -> Result<[u8; EXTENDED_BYTES], String> {
-    let sig_bytes = get_sig_bytes(msg, key)?;
+    let sig_bytes = get_sig_bytes(msg, key).await?;
     Ok(extend_to_64_bytes(&sig_bytes))
 }
 
@@ -154,3 +181,11 @@ fn extend_to_64_bytes(bytes: &[u8]) -> [u8; EXTENDED_BYTES] {
     extended[KECCAK_HASH_BYTES..].copy_from_slice(&top_bytes.0);
     extended
 }
+
+/// Get the current Unix timestamp in seconds
+pub(crate) fn unix_now() -> Result<u64, RenegadeClientError> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .map_err(RenegadeClientError::custom)
+}
@@ -0,0 +1,181 @@
+//! A read-only client for an account
+//!
+//! The view client holds only the keys needed to read an account's state: the
+//! account ID, the HMAC key authenticating API requests, and the master view
+//! seed. It never holds the account's signing key or Schnorr key, and exposes
+//! no actions that require them, so a leaked set of view keys cannot be used
+//! to move funds.
+
+use std::{fmt, time::Duration};
+
+use futures_util::Stream;
+use renegade_constants::Scalar;
+use renegade_external_api::types::websocket::{
+    BalanceUpdateMessage, FillMessage, OrderUpdateMessage, TaskUpdateMessage,
+};
+use uuid::Uuid;
+
+use crate::{
+    HmacKey, RenegadeClientError,
+    account_state::AccountState,
+    http::RelayerHttpClient,
+    network::NetworkConfig,
+    renegade_wallet_client::client::AccountSecrets,
+    websocket::{RenegadeWebsocketClient, TaskWaiter},
+};
+
+// -------------
+// | View Keys |
+// -------------
+
+/// The keys needed to read an account's state
+///
+/// The keys are redacted from `Debug` output, and the HMAC key is zeroized on
+/// drop.
+#[derive(Clone)]
+pub struct ViewKeys {
+    /// The ID of the account
+    pub account_id: Uuid,
+    /// The HMAC key used to authenticate account API requests
+    pub auth_hmac_key: HmacKey,
+    /// The master view seed, used to sync the account with onchain state
+    pub master_view_seed: Scalar,
+}
+
+impl From<&AccountSecrets> for ViewKeys {
+    fn from(secrets: &AccountSecrets) -> Self {
+        Self {
+            account_id: secrets.account_id,
+            auth_hmac_key: secrets.auth_hmac_key.clone(),
+            master_view_seed: secrets.master_view_seed,
+        }
+    }
+}
+
+impl fmt::Debug for ViewKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ViewKeys")
+            .field("account_id", &self.account_id)
+            .field("auth_hmac_key", &"[REDACTED]")
+            .field("master_view_seed", &"[REDACTED]")
+            .finish()
+    }
+}
+
+// ---------------
+// | View Client |
+// ---------------
+
+/// A read-only Renegade wallet client
+///
+/// Reads the account's balances, orders, and tasks, and subscribes to its
+/// websocket topics. The client cannot create, deposit to, withdraw from, or
+/// place orders in the account.
+#[derive(Clone)]
+pub struct RenegadeViewClient {
+    /// The view keys
+    keys: ViewKeys,
+    /// The relayer HTTP client
+    pub(crate) relayer_client: RelayerHttpClient,
+    /// The websocket client
+    pub(crate) websocket_client: RenegadeWebsocketClient,
+}
+
+impl RenegadeViewClient {
+    /// Create a view client for the account on the given network
+    pub fn new(network: impl Into<NetworkConfig>, keys: ViewKeys) -> Self {
+        let network = network.into();
+        Self::from_relayer_url(&network.relayer_base_url, keys)
+    }
+
+    /// Create a view client for the account on the relayer at the given URL
+    pub fn from_relayer_url(relayer_base_url: &str, keys: ViewKeys) -> Self {
+        let relayer_client =
+            RelayerHttpClient::new(relayer_base_url.to_string(), keys.auth_hmac_key.clone());
+        let websocket_client = RenegadeWebsocketClient::from_relayer_url(
+            relayer_base_url,
+            keys.account_id,
+            keys.auth_hmac_key.clone(),
+            None, // admin_hmac_key
+        );
+
+        Self::from_parts(keys, relayer_client, websocket_client)
+    }
+
+    /// Create a view client from existing HTTP & websocket clients for the
+    /// account, sharing their connections
+    pub(crate) fn from_parts(
+        keys: ViewKeys,
+        relayer_client: RelayerHttpClient,
+        websocket_client: RenegadeWebsocketClient,
+    ) -> Self {
+        Self { keys, relayer_client, websocket_client }
+    }
+
+    // --------------
+    // | WS Methods |
+    // --------------
+
+    /// Create a `TaskWaiter` which can be used to watch a task until it
+    /// completes or times out
+    pub async fn watch_task(
+        &self,
+        task_id: Uuid,
+        timeout: Duration,
+    ) -> Result<TaskWaiter, RenegadeClientError> {
+        self.websocket_client.watch_task(task_id, timeout).await
+    }
+
+    /// Load an in-memory mirror of the account's state, kept up to date by
+    /// websocket updates
+    pub async fn account_state(&self) -> Result<AccountState, RenegadeClientError> {
+        AccountState::new(self.clone()).await
+    }
+
+    /// Subscribe to the account's task updates stream
+    pub async fn subscribe_task_updates(
+        &self,
+    ) -> Result<impl Stream<Item = TaskUpdateMessage> + use<>, RenegadeClientError> {
+        self.websocket_client.subscribe_task_updates().await
+    }
+
+    /// Subscribe to the account's balance updates stream
+    pub async fn subscribe_balance_updates(
+        &self,
+    ) -> Result<impl Stream<Item = BalanceUpdateMessage> + use<>, RenegadeClientError> {
+        self.websocket_client.subscribe_balance_updates().await
+    }
+
+    /// Subscribe to the account's order updates stream
+    pub async fn subscribe_order_updates(
+        &self,
+    ) -> Result<impl Stream<Item = OrderUpdateMessage> + use<>, RenegadeClientError> {
+        self.websocket_client.subscribe_order_updates().await
+    }
+
+    /// Subscribe to the account's fills stream
+    pub async fn subscribe_fills(
+        &self,
+    ) -> Result<impl Stream<Item = FillMessage> + use<>, RenegadeClientError> {
+        self.websocket_client.subscribe_fills().await
+    }
+
+    // --------------
+    // | Misc Utils |
+    // --------------
+
+    /// Get the ID of the account
+    pub fn get_account_id(&self) -> Uuid {
+        self.keys.account_id
+    }
+
+    /// Get the master view seed
+    pub fn get_master_view_seed(&self) -> Scalar {
+        self.keys.master_view_seed
+    }
+
+    /// Get the view keys the client is configured with
+    pub fn view_keys(&self) -> &ViewKeys {
+        &self.keys
+    }
+}
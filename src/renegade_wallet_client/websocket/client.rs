@@ -15,6 +15,7 @@ use tokio::net::TcpStream;
 use tokio::sync::{
     OnceCell as AsyncOnceCell, RwLock,
     mpsc::{self, UnboundedReceiver, UnboundedSender},
+    watch,
 };
 use tokio_stream::StreamExt;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
@@ -87,22 +88,33 @@ pub struct RenegadeWebsocketClient {
     admin_hmac_key: Option<HmacKey>,
     /// The topic subscription manager. This is lazily initialized along with
     /// the underlying websocket connection when the first subscription
-    /// request is made.
-    subscriptions: OnceLock<Arc<SubscriptionManager>>,
+    /// request is made. The cell is shared between clones of the client, so
+    /// that they share one connection.
+    subscriptions: Arc<OnceLock<Arc<SubscriptionManager>>>,
     /// The task waiter manager. This is lazily initialized when the first task
-    /// waiter is created.
-    task_waiter_manager: AsyncOnceCell<Arc<TaskWaiterManager>>,
+    /// waiter is created, and shared between clones of the client.
+    task_waiter_manager: Arc<AsyncOnceCell<Arc<TaskWaiterManager>>>,
 }
 
 impl RenegadeWebsocketClient {
     /// Create a new websocket client
-    pub fn new(
-        config: &RenegadeClientConfig,
+    pub fn new<S>(
+        config: &RenegadeClientConfig<S>,
         account_id: Uuid,
         auth_hmac_key: HmacKey,
         admin_hmac_key: Option<HmacKey>,
     ) -> Self {
-        let base_url = config.relayer_base_url.replace("http", "ws");
+        Self::from_relayer_url(&config.relayer_base_url, account_id, auth_hmac_key, admin_hmac_key)
+    }
+
+    /// Create a new websocket client for the relayer at the given base URL
+    pub fn from_relayer_url(
+        relayer_base_url: &str,
+        account_id: Uuid,
+        auth_hmac_key: HmacKey,
+        admin_hmac_key: Option<HmacKey>,
+    ) -> Self {
+        let base_url = relayer_base_url.replace("http", "ws");
         let base_url = format!("{base_url}:{DEFAULT_WS_PORT}");
 
         Self {
@@ -110,8 +122,8 @@ impl RenegadeWebsocketClient {
             account_id,
             auth_hmac_key,
             admin_hmac_key,
-            subscriptions: OnceLock::new(),
-            task_waiter_manager: AsyncOnceCell::new(),
+            subscriptions: Arc::new(OnceLock::new()),
+            task_waiter_manager: Arc::new(AsyncOnceCell::new()),
         }
     }
 
@@ -120,13 +132,42 @@ impl RenegadeWebsocketClient {
     // -----------------
 
     /// Subscribe to a new websocket topic
-    async fn subscribe_to_topic(&self, topic: String) -> Result<TopicStream, RenegadeClientError> {
+    pub(crate) async fn subscribe_to_topic(
+        &self,
+        topic: String,
+    ) -> Result<TopicStream, RenegadeClientError> {
         self.ensure_subscriptions_initialized();
 
         let subscriptions = self.subscriptions.get().unwrap();
         subscriptions.subscribe_to_topic(topic).await
     }
 
+    /// Watch for reconnections of the websocket connection
+    ///
+    /// The watched value is the number of times the connection has been
+    /// re-established. It is updated once all topics have been resubscribed
+    /// to, after which messages missed while disconnected will not be
+    /// redelivered.
+    pub fn watch_reconnects(&self) -> watch::Receiver<u64> {
+        self.ensure_subscriptions_initialized();
+
+        let subscriptions = self.subscriptions.get().unwrap();
+        subscriptions.watch_reconnects()
+    }
+
+    /// Watch whether the websocket connection is currently established
+    ///
+    /// The watched value is `false` until the first connection is established,
+    /// & while the connection is being re-established. It becomes `true` once
+    /// the connection is established & resubscriptions to all topics have been
+    /// queued; the relayer may not have confirmed them yet.
+    pub fn watch_connection(&self) -> watch::Receiver<bool> {
+        self.ensure_subscriptions_initialized();
+
+        let subscriptions = self.subscriptions.get().unwrap();
+        subscriptions.watch_connection()
+    }
+
     // --- Tasks --- //
 
     /// Subscribe to the account's task updates stream
@@ -155,7 +196,7 @@ impl RenegadeWebsocketClient {
     /// Subscribe to the account's balance updates stream
     pub async fn subscribe_balance_updates(
         &self,
-    ) -> Result<impl Stream<Item = BalanceUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = BalanceUpdateMessage> + use<>, RenegadeClientError> {
         let stream = self.subscribe_to_topic(self.balances_topic()).await?;
 
         let filtered_stream = stream.filter_map(|maybe_ws_msg| {
@@ -169,7 +210,7 @@ impl RenegadeWebsocketClient {
     }
 
     /// Construct the account's balance updates topic name
-    fn balances_topic(&self) -> String {
+    pub(crate) fn balances_topic(&self) -> String {
         format!("/v2/account/{}/balances", self.account_id)
     }
 
@@ -178,7 +219,7 @@ impl RenegadeWebsocketClient {
     /// Subscribe to the account's order updates stream
     pub async fn subscribe_order_updates(
         &self,
-    ) -> Result<impl Stream<Item = OrderUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = OrderUpdateMessage> + use<>, RenegadeClientError> {
         let stream = self.subscribe_to_topic(self.orders_topic()).await?;
 
         let filtered_stream = stream.filter_map(|maybe_ws_msg| {
@@ -192,7 +233,7 @@ impl RenegadeWebsocketClient {
     }
 
     /// Construct the account's order updates topic name
-    fn orders_topic(&self) -> String {
+    pub(crate) fn orders_topic(&self) -> String {
         format!("/v2/account/{}/orders", self.account_id)
     }
 
@@ -201,7 +242,7 @@ impl RenegadeWebsocketClient {
     /// Subscribe to the account's fills stream
     pub async fn subscribe_fills(
         &self,
-    ) -> Result<impl Stream<Item = FillMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = FillMessage> + use<>, RenegadeClientError> {
         let stream = self.subscribe_to_topic(self.fills_topic()).await?;
 
         let filtered_stream = stream.filter_map(|maybe_ws_msg| {
@@ -215,7 +256,7 @@ impl RenegadeWebsocketClient {
     }
 
     /// Construct the account's fills topic name
-    fn fills_topic(&self) -> String {
+    pub(crate) fn fills_topic(&self) -> String {
         format!("/v2/account/{}/fills", self.account_id)
     }
 
@@ -224,7 +265,7 @@ impl RenegadeWebsocketClient {
     /// Subscribe to the admin balances updates stream
     pub async fn subscribe_admin_balance_updates(
         &self,
-    ) -> Result<impl Stream<Item = AdminBalanceUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = AdminBalanceUpdateMessage> + use<>, RenegadeClientError> {
         let stream = self.subscribe_to_topic(ADMIN_BALANCES_TOPIC.to_string()).await?;
 
         let filtered_stream = stream.filter_map(|maybe_ws_msg| {
@@ -240,7 +281,7 @@ impl RenegadeWebsocketClient {
     /// Subscribe to the admin order updates stream
     pub async fn subscribe_admin_order_updates(
         &self,
-    ) -> Result<impl Stream<Item = AdminOrderUpdateMessage>, RenegadeClientError> {
+    ) -> Result<impl Stream<Item = AdminOrderUpdateMessage> + use<>, RenegadeClientError> {
         let stream = self.subscribe_to_topic(ADMIN_ORDERS_TOPIC.to_string()).await?;
 
         let filtered_stream = stream.filter_map(|maybe_ws_msg| {
@@ -295,8 +336,8 @@ impl RenegadeWebsocketClient {
         self.subscriptions.get_or_init(|| {
             let (subscriptions_tx, subscriptions_rx) = create_subscription_channel();
             let subscriptions = Arc::new(SubscriptionManager::new(
-                self.auth_hmac_key,
-                self.admin_hmac_key,
+                self.auth_hmac_key.clone(),
+                self.admin_hmac_key.clone(),
                 subscriptions_tx,
             ));
 
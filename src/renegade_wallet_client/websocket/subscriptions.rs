@@ -1,7 +1,11 @@
 //! The websocket client's subscriptions manager, which handles subscribing to
 //! different relayer topics and streaming them out separately
 
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 
 use crate::auth::HmacKey;
 use crate::auth::add_expiring_auth_to_headers;
@@ -15,13 +19,14 @@ use tokio::sync::{
     RwLock,
     broadcast::{self, Receiver as BroadcastReceiver, Sender as BroadcastSender},
     mpsc::{UnboundedReceiver, UnboundedSender},
+    watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{error, info, warn};
 
 use crate::{
-    RenegadeClientError,
+    RenegadeClientError, telemetry,
     websocket::{ADMIN_BALANCES_TOPIC, ADMIN_ORDERS_TOPIC, WsSink, WsStream},
 };
 
@@ -93,6 +98,13 @@ pub struct SubscriptionManager {
     subscriptions_tx: SubscriptionTx,
     /// The map of subscribed topics
     subscribed_topics: SubscriptionMap,
+    /// Whether the manager has previously managed a websocket connection, used
+    /// to distinguish reconnections from the initial connection
+    has_connected: AtomicBool,
+    /// The number of times the websocket connection has been re-established
+    reconnects: WatchSender<u64>,
+    /// Whether the websocket connection is currently established
+    connected: WatchSender<bool>,
 }
 
 impl SubscriptionManager {
@@ -107,9 +119,23 @@ impl SubscriptionManager {
             admin_hmac_key,
             subscriptions_tx,
             subscribed_topics: RwLock::new(HashMap::new()),
+            has_connected: AtomicBool::new(false),
+            reconnects: WatchSender::new(0),
+            connected: WatchSender::new(false),
         }
     }
 
+    /// Watch the number of times the websocket connection has been
+    /// re-established
+    pub fn watch_reconnects(&self) -> WatchReceiver<u64> {
+        self.reconnects.subscribe()
+    }
+
+    /// Watch whether the websocket connection is currently established
+    pub fn watch_connection(&self) -> WatchReceiver<bool> {
+        self.connected.subscribe()
+    }
+
     /// Subscribe to the given topic
     pub async fn subscribe_to_topic(
         &self,
@@ -175,10 +201,21 @@ impl SubscriptionManager {
         subscriptions_rx: &mut SubscriptionRx,
     ) {
         let (mut ws_tx, mut ws_rx) = ws_stream.split();
+        let is_reconnect = self.has_connected.swap(true, Ordering::Relaxed);
+        if is_reconnect {
+            telemetry::record_websocket_reconnect();
+        }
 
         // Re-send subscription requests to the server for all active subscriptions
         self.resubscribe_to_all_topics().await.unwrap();
 
+        // Notify listeners of the reconnection, so that they may recover messages
+        // missed while disconnected
+        if is_reconnect {
+            self.reconnects.send_modify(|count| *count += 1);
+        }
+        self.connected.send_replace(true);
+
         loop {
             tokio::select! {
                 // Handle incoming subscription requests from the client.
@@ -231,6 +268,8 @@ impl SubscriptionManager {
                 }
             }
         }
+
+        self.connected.send_replace(false);
     }
 
     /// Re-send subscription requests to the server for all active subscriptions
@@ -311,6 +350,7 @@ impl SubscriptionManager {
 
         if let Some(tx) = self.try_get_subscription(&msg.topic).await {
             tx.send(msg.body).map_err(RenegadeClientError::subscription)?;
+            telemetry::record_topic_lag(&msg.topic, tx.len());
         }
 
         Ok(())
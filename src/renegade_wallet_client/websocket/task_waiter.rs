@@ -1,8 +1,10 @@
 //! A task waiter is a structure that waits for a task to complete then
-//! transforms the status into a result
+//! transforms its final state into a result, reporting the task's
+//! intermediate states along the way
 
 use std::{
     collections::HashMap,
+    fmt,
     future::Future,
     pin::Pin,
     sync::Arc,
@@ -10,17 +12,14 @@ use std::{
     time::Duration,
 };
 
-use futures_util::{FutureExt, Stream, future::BoxFuture};
+use futures_util::{FutureExt, Stream, future::BoxFuture, stream};
 use renegade_external_api::types::{ApiTask, TaskUpdateMessage};
-use tokio::sync::{
-    RwLock,
-    oneshot::{self, Receiver as OneshotReceiver, Sender as OneshotSender},
-};
+use tokio::sync::{RwLock, watch};
 use tokio_stream::StreamExt;
 use tracing::error;
 use uuid::Uuid;
 
-use crate::RenegadeClientError;
+use crate::{RenegadeClientError, telemetry};
 
 // -------------
 // | Constants |
@@ -29,52 +28,102 @@ use crate::RenegadeClientError;
 /// The timeout for a task to complete
 pub const DEFAULT_TASK_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// The relayer's name for the state of a task waiting in its queue
+const QUEUED_STATE: &str = "Queued";
+/// The prefix of the relayer's description of a completed task
+const COMPLETED_STATE: &str = "Completed";
+/// The prefix of the relayer's description of a failed task, which is followed
+/// by the task's error
+const FAILED_STATE: &str = "Failed";
+
 // ----------------
 // | Type Aliases |
 // ----------------
 
-/// A oneshot channel on which to send task status notifications
-type TaskNotificationTx = OneshotSender<TaskStatusNotification>;
-/// A oneshot channel on which to receive task status notifications
-type TaskNotificationRx = OneshotReceiver<TaskStatusNotification>;
+/// A watch channel on which to send a task's latest update
+type TaskUpdateTx = watch::Sender<Option<ApiTask>>;
+/// A watch channel on which to receive a task's latest update
+type TaskUpdateRx = watch::Receiver<Option<ApiTask>>;
 
-/// A map of task IDs to their corresponding notification channels
-type NotificationMap = Arc<RwLock<HashMap<Uuid, TaskNotificationTx>>>;
+/// A map of task IDs to their corresponding update channels
+type UpdateMap = Arc<RwLock<HashMap<Uuid, TaskUpdateTx>>>;
 
 /// The future type for a task waiter
-type TaskWaiterFuture = BoxFuture<'static, Result<(), RenegadeClientError>>;
+type TaskWaiterFuture = BoxFuture<'static, Result<ApiTask, RenegadeClientError>>;
 
 // -------------------
 // | Channel Helpers |
 // -------------------
 
-/// Create a new notification channel
-pub fn create_notification_channel() -> (TaskNotificationTx, TaskNotificationRx) {
-    oneshot::channel()
+/// Create a new task update channel, holding no update
+pub fn create_task_update_channel() -> (TaskUpdateTx, TaskUpdateRx) {
+    watch::channel(None)
 }
 
 // ---------
 // | Types |
 // ---------
 
-/// A task status notification
+/// The state of a relayer task
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum TaskStatusNotification {
-    /// A task has been completed
-    Success,
-    /// A task has failed
+pub enum TaskState {
+    /// The task is waiting in the relayer's queue
+    Queued,
+    /// The task is running
+    Running {
+        /// The step the task is in, as described by the relayer
+        step: String,
+    },
+    /// The task has completed
+    Completed,
+    /// The task has failed
     Failed {
         /// The error message
         error: String,
     },
 }
 
-impl TaskStatusNotification {
-    /// Convert the task status into a Result<(), RenegadeClientError>
-    pub fn into_result(self, task_id: Uuid) -> Result<(), RenegadeClientError> {
+impl TaskState {
+    /// Parse a task state from the relayer's description of it
+    ///
+    /// The relayer names the queued state, prefixes the completed & failed
+    /// states with their names, ignoring case, & describes a running task by
+    /// its current step. A failed task's description, including the error the
+    /// relayer appends to it, is kept as the error.
+    pub fn parse(state: &str) -> Self {
+        let starts_with = |name: &str| {
+            state.get(..name.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+        };
+        if state.eq_ignore_ascii_case(QUEUED_STATE) {
+            Self::Queued
+        } else if starts_with(COMPLETED_STATE) {
+            Self::Completed
+        } else if starts_with(FAILED_STATE) {
+            Self::Failed { error: state.to_string() }
+        } else {
+            Self::Running { step: state.to_string() }
+        }
+    }
+
+    /// Whether the task has completed or failed
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed { .. })
+    }
+}
+
+impl From<&ApiTask> for TaskState {
+    fn from(task: &ApiTask) -> Self {
+        Self::parse(&task.state)
+    }
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Success => Ok(()),
-            Self::Failed { error } => Err(RenegadeClientError::task(task_id, error)),
+            Self::Queued => write!(f, "queued"),
+            Self::Running { step } => write!(f, "{step}"),
+            Self::Completed => write!(f, "completed"),
+            Self::Failed { error } => write!(f, "{error}"),
         }
     }
 }
@@ -83,11 +132,11 @@ impl TaskStatusNotification {
 // | Task Waiter Manager |
 // -----------------------
 
-/// Manages sending notifications to task waiters
+/// Manages forwarding task updates to task waiters
 #[derive(Clone)]
 pub struct TaskWaiterManager {
-    /// The notification map
-    notifications: NotificationMap,
+    /// The update channels of the tasks being awaited
+    updates: UpdateMap,
 }
 
 impl TaskWaiterManager {
@@ -96,7 +145,7 @@ impl TaskWaiterManager {
     where
         S: Stream<Item = TaskUpdateMessage> + Unpin + Send + 'static,
     {
-        let this = Self { notifications: Arc::new(RwLock::new(HashMap::new())) };
+        let this = Self { updates: Arc::new(RwLock::new(HashMap::new())) };
 
         let this_clone = this.clone();
         tokio::spawn(async move { this_clone.watch_task_updates(tasks_topic).await });
@@ -106,14 +155,13 @@ impl TaskWaiterManager {
 
     /// Create a task waiter which can be awaited until the given task completes
     pub async fn create_task_waiter(&self, task_id: Uuid, timeout: Duration) -> TaskWaiter {
-        let (tx, rx) = create_notification_channel();
-        self.notifications.write().await.insert(task_id, tx);
+        let (tx, rx) = create_task_update_channel();
+        self.updates.write().await.insert(task_id, tx);
         TaskWaiter::new(task_id, rx, timeout)
     }
 
-    /// A persistent loop which watches for task updates and forward the task
-    /// status notification to the appropriate receiver if the task's status
-    /// is being awaited
+    /// A persistent loop which watches for task updates and forwards them to
+    /// the task's waiter, if the task is being awaited
     async fn watch_task_updates<S>(&self, mut tasks_topic: S)
     where
         S: Stream<Item = TaskUpdateMessage> + Unpin,
@@ -125,42 +173,24 @@ impl TaskWaiterManager {
         error!("Task update stream closed");
     }
 
-    /// Handle a task update, forwarding the task status notification to the
-    /// appropriate receiver if the task's status is being awaited
+    /// Handle a task update, forwarding it to the task's waiter if the task is
+    /// being awaited
+    ///
+    /// The waiter is forgotten once the task completes or fails
     async fn handle_task_update(&self, task: ApiTask) {
-        let ApiTask { id, state, .. } = task;
-        let state = state.to_lowercase();
-        if state.contains("completed") {
-            self.handle_completed_task(id).await;
-        } else if state.contains("failed") {
-            self.handle_failed_task(id, state).await;
-        }
-    }
-
-    /// Handle a completed task, forwarding a success notification
-    async fn handle_completed_task(&self, task_id: Uuid) {
-        let mut notifications = self.notifications.write().await;
+        let mut updates = self.updates.write().await;
+        let is_terminal = TaskState::from(&task).is_terminal();
+        let id = task.id;
 
-        let tx = match notifications.remove(&task_id) {
-            Some(tx) => tx,
-            None => return,
+        let Some(tx) = updates.get(&id) else {
+            return;
         };
 
-        // We explicitly ignore errors here in case the receiver is dropped
-        let _ = tx.send(TaskStatusNotification::Success);
-    }
-
-    /// Handle a failed task, forwarding a failure notification
-    async fn handle_failed_task(&self, task_id: Uuid, error: String) {
-        let mut notifications = self.notifications.write().await;
-
-        let tx = match notifications.remove(&task_id) {
-            Some(tx) => tx,
-            None => return,
-        };
-
-        // We explicitly ignore errors here in case the receiver is dropped
-        let _ = tx.send(TaskStatusNotification::Failed { error });
+        // We explicitly ignore errors here in case the receivers are dropped
+        let _ = tx.send(Some(task));
+        if is_terminal {
+            updates.remove(&id);
+        }
     }
 }
 
@@ -168,14 +198,14 @@ impl TaskWaiterManager {
 // | Task Waiter |
 // ---------------
 
-/// A thin wrapper around a notification channel that waits for a task to
-/// complete then transforms the status into a result
+/// A thin wrapper around a task's update channel that waits for the task to
+/// complete then resolves to the final task, or to the task's error
 pub struct TaskWaiter {
     /// The task ID
     task_id: Uuid,
-    /// The task status notification receiver.
+    /// The task update receiver.
     /// This will be `taken` once the task waiter is first polled.
-    notification_rx: Option<TaskNotificationRx>,
+    update_rx: Option<TaskUpdateRx>,
     /// The duration to wait for the task to complete before timing out
     timeout: Duration,
     /// The underlying future that waits for the task to complete
@@ -184,38 +214,130 @@ pub struct TaskWaiter {
 
 impl TaskWaiter {
     /// Create a new task waiter
-    pub fn new(task_id: Uuid, notification_rx: TaskNotificationRx, timeout: Duration) -> Self {
-        Self { task_id, notification_rx: Some(notification_rx), timeout, fut: None }
+    pub fn new(task_id: Uuid, update_rx: TaskUpdateRx, timeout: Duration) -> Self {
+        Self { task_id, update_rx: Some(update_rx), timeout, fut: None }
+    }
+
+    /// The ID of the awaited task
+    pub fn task_id(&self) -> Uuid {
+        self.task_id
+    }
+
+    /// Stream the states the task passes through, ending with the state in
+    /// which it completes or fails
+    ///
+    /// The stream begins with the task's latest state, if any update has been
+    /// received. A consumer that falls behind skips to the latest state. Must
+    /// be called before the waiter is first polled; the stream is empty
+    /// otherwise.
+    pub fn progress(&self) -> impl Stream<Item = TaskState> + Send + use<> {
+        stream::unfold(self.update_rx.clone(), |update_rx| async move {
+            let mut update_rx = update_rx?;
+            let state = TaskState::from(&Self::next_update(&mut update_rx).await?);
+
+            // End the stream after the terminal state
+            let update_rx = (!state.is_terminal()).then_some(update_rx);
+            Some((state, update_rx))
+        })
     }
 
     /// Watch a task until it terminates as a success or failure
     async fn watch_task(
         task_id: Uuid,
-        notification_rx: TaskNotificationRx,
+        update_rx: TaskUpdateRx,
         timeout: Duration,
-    ) -> Result<(), RenegadeClientError> {
-        // Register a notification channel for the task and await
-        let timeout = tokio::time::timeout(timeout, notification_rx);
-        let notification = timeout
+    ) -> Result<ApiTask, RenegadeClientError> {
+        let timeout = tokio::time::timeout(timeout, Self::wait_for_terminal_update(update_rx));
+        let task = timeout
             .await
-            .map_err(|_| RenegadeClientError::task(task_id, "Task timed out"))?
-            .map_err(|_| RenegadeClientError::task(task_id, "Task waiter closed"))?;
+            .map_err(|_| {
+                telemetry::record_task_timeout();
+                RenegadeClientError::task(task_id, "Task timed out")
+            })?
+            .ok_or_else(|| RenegadeClientError::task(task_id, "Task waiter closed"))?;
+
+        match TaskState::from(&task) {
+            TaskState::Failed { error } => Err(RenegadeClientError::task(task_id, error)),
+            _ => Ok(task),
+        }
+    }
 
-        notification.into_result(task_id)
+    /// Wait for the update in which the task completes or fails, returning
+    /// `None` if the update channel closes first
+    async fn wait_for_terminal_update(mut update_rx: TaskUpdateRx) -> Option<ApiTask> {
+        loop {
+            let task = update_rx.borrow_and_update().clone();
+            if let Some(task) = task.filter(|task| TaskState::from(task).is_terminal()) {
+                return Some(task);
+            }
+
+            update_rx.changed().await.ok()?;
+        }
+    }
+
+    /// Wait for an update not yet seen by the receiver, returning `None` if
+    /// the update channel closes first
+    async fn next_update(update_rx: &mut TaskUpdateRx) -> Option<ApiTask> {
+        loop {
+            update_rx.changed().await.ok()?;
+            let task = update_rx.borrow_and_update().clone();
+            if task.is_some() {
+                return task;
+            }
+        }
     }
 }
 
 impl Future for TaskWaiter {
-    type Output = Result<(), RenegadeClientError>;
+    type Output = Result<ApiTask, RenegadeClientError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
         if this.fut.is_none() {
-            let notification_rx = this.notification_rx.take().unwrap();
-            let fut = Self::watch_task(this.task_id, notification_rx, this.timeout).boxed();
+            let update_rx = this.update_rx.take().unwrap();
+            let fut = Self::watch_task(this.task_id, update_rx, this.timeout).boxed();
             this.fut = Some(fut);
         }
 
         this.fut.as_mut().unwrap().as_mut().poll(cx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_states() {
+        assert_eq!(TaskState::parse("Queued"), TaskState::Queued);
+        assert_eq!(TaskState::parse("Completed"), TaskState::Completed);
+        assert_eq!(TaskState::parse("completed"), TaskState::Completed);
+        assert_eq!(TaskState::parse("Failed"), TaskState::Failed { error: "Failed".to_string() });
+    }
+
+    #[test]
+    fn test_parse_failure_with_error() {
+        let state = "Failed: error proving VALID COMMITMENTS: insufficient balance";
+        let parsed = TaskState::parse(state);
+        assert_eq!(parsed, TaskState::Failed { error: state.to_string() });
+        assert!(parsed.is_terminal());
+        assert_eq!(parsed.to_string(), state);
+    }
+
+    #[test]
+    fn test_parse_running_steps() {
+        // Steps that mention a terminal state are not themselves terminal
+        for step in ["Proving", "Awaiting completed proofs", "Retrying failed submission"] {
+            let state = TaskState::parse(step);
+            assert_eq!(state, TaskState::Running { step: step.to_string() });
+            assert!(!state.is_terminal());
+        }
+    }
+
+    #[test]
+    fn test_terminal_states() {
+        assert!(TaskState::parse("Completed").is_terminal());
+        assert!(TaskState::parse("Failed").is_terminal());
+        assert!(!TaskState::parse("Queued").is_terminal());
+    }
+}
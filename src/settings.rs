@@ -0,0 +1,364 @@
+//! Loading client settings from the environment or a config file
+//!
+//! [`ClientSettings`] collects the network selection, URL overrides and
+//! credentials used to build either client. The same settings may be read
+//! from environment variables or from a TOML or JSON file:
+//!
+//! | File key               | Environment variable            |
+//! | ---------------------- | ------------------------------- |
+//! | `network`              | `RENEGADE_NETWORK`              |
+//! | `relayer_url`          | `RENEGADE_RELAYER_URL`          |
+//! | `auth_url`             | `RENEGADE_AUTH_URL`             |
+//! | `historical_state_url` | `RENEGADE_HISTORICAL_STATE_URL` |
+//! | `admin_key`            | `RENEGADE_ADMIN_KEY`            |
+//! | `api_key`              | `EXTERNAL_MATCH_KEY`            |
+//! | `api_secret`           | `EXTERNAL_MATCH_SECRET`         |
+//! | `private_key`          | `PKEY`                          |
+//! | `keystore`             | `RENEGADE_KEYSTORE`             |
+//! | `keystore_password`    | `RENEGADE_KEYSTORE_PASSWORD`    |
+
+use std::{
+    env::{self, VarError},
+    ffi::OsStr,
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use alloy::signers::local::PrivateKeySigner;
+use serde::Deserialize;
+use url::Url;
+use zeroize::Zeroizing;
+
+use crate::{
+    auth::HmacKey,
+    network::{Network, NetworkConfig},
+};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The network setting
+const NETWORK: Setting = Setting { key: "network", env: "RENEGADE_NETWORK" };
+/// The relayer URL override setting
+const RELAYER_URL: Setting = Setting { key: "relayer_url", env: "RENEGADE_RELAYER_URL" };
+/// The auth server URL override setting
+const AUTH_URL: Setting = Setting { key: "auth_url", env: "RENEGADE_AUTH_URL" };
+/// The historical state URL override setting
+const HISTORICAL_STATE_URL: Setting =
+    Setting { key: "historical_state_url", env: "RENEGADE_HISTORICAL_STATE_URL" };
+/// The admin HMAC key setting
+const ADMIN_KEY: Setting = Setting { key: "admin_key", env: "RENEGADE_ADMIN_KEY" };
+/// The external match API key setting
+const API_KEY: Setting = Setting { key: "api_key", env: "EXTERNAL_MATCH_KEY" };
+/// The external match API secret setting
+const API_SECRET: Setting = Setting { key: "api_secret", env: "EXTERNAL_MATCH_SECRET" };
+/// The private key setting
+const PRIVATE_KEY: Setting = Setting { key: "private_key", env: "PKEY" };
+/// The keystore path setting
+const KEYSTORE: Setting = Setting { key: "keystore", env: "RENEGADE_KEYSTORE" };
+/// The keystore password setting
+const KEYSTORE_PASSWORD: Setting =
+    Setting { key: "keystore_password", env: "RENEGADE_KEYSTORE_PASSWORD" };
+
+// ---------
+// | Error |
+// ---------
+
+/// An error loading client settings
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    /// A required setting is missing
+    #[error("missing {0}")]
+    Missing(String),
+    /// A setting has an invalid value
+    #[error("invalid {name}: {message}")]
+    Invalid {
+        /// The setting, named as it was provided
+        name: String,
+        /// The reason the value is invalid
+        message: String,
+    },
+    /// A config file could not be read or parsed
+    #[error("failed to load config file {path}: {message}")]
+    File {
+        /// The path to the config file
+        path: String,
+        /// The error message
+        message: String,
+    },
+}
+
+impl ConfigError {
+    /// Create a new file error
+    #[allow(clippy::needless_pass_by_value)]
+    fn file<T: ToString>(path: &Path, msg: T) -> Self {
+        Self::File { path: path.display().to_string(), message: msg.to_string() }
+    }
+}
+
+// ------------
+// | Settings |
+// ------------
+
+/// A setting, named by its config file key and environment variable
+struct Setting {
+    /// The key of the setting in a config file
+    key: &'static str,
+    /// The environment variable holding the setting
+    env: &'static str,
+}
+
+/// Where a set of settings was loaded from
+#[derive(Clone, Debug, Default)]
+enum Source {
+    /// Environment variables
+    #[default]
+    Env,
+    /// A config file at the given path
+    File(PathBuf),
+}
+
+impl Source {
+    /// Name a setting as it appears in this source
+    fn describe(&self, setting: &Setting) -> String {
+        match self {
+            Source::Env => format!("environment variable {}", setting.env),
+            Source::File(path) => format!("`{}` in {}", setting.key, path.display()),
+        }
+    }
+}
+
+/// Settings for building the external match and wallet clients
+///
+/// Fields may be modified after loading, e.g. to override a value from the
+/// environment
+#[derive(Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientSettings {
+    /// The network to connect to, e.g. `arbitrum-sepolia`
+    pub network: Option<String>,
+    /// Overrides the network's relayer URL
+    pub relayer_url: Option<String>,
+    /// Overrides the network's auth server URL
+    pub auth_url: Option<String>,
+    /// Overrides the network's historical state URL
+    pub historical_state_url: Option<String>,
+    /// The base64 encoded admin HMAC key
+    pub admin_key: Option<Zeroizing<String>>,
+    /// The external match API key
+    pub api_key: Option<String>,
+    /// The base64 encoded external match API secret
+    pub api_secret: Option<Zeroizing<String>>,
+    /// The hex encoded private key of the account
+    pub private_key: Option<Zeroizing<String>>,
+    /// The path to an encrypted JSON keystore holding the account's private
+    /// key. Takes precedence over `private_key`
+    pub keystore: Option<PathBuf>,
+    /// The password used to decrypt the keystore
+    pub keystore_password: Option<Zeroizing<String>>,
+    /// Where the settings were loaded from
+    #[serde(skip)]
+    source: Source,
+}
+
+impl fmt::Debug for ClientSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redact = |is_set: bool| is_set.then_some("[REDACTED]");
+        f.debug_struct("ClientSettings")
+            .field("network", &self.network)
+            .field("relayer_url", &self.relayer_url)
+            .field("auth_url", &self.auth_url)
+            .field("historical_state_url", &self.historical_state_url)
+            .field("admin_key", &redact(self.admin_key.is_some()))
+            .field("api_key", &redact(self.api_key.is_some()))
+            .field("api_secret", &redact(self.api_secret.is_some()))
+            .field("private_key", &redact(self.private_key.is_some()))
+            .field("keystore", &self.keystore)
+            .field("keystore_password", &redact(self.keystore_password.is_some()))
+            .finish()
+    }
+}
+
+impl ClientSettings {
+    /// Load settings from environment variables
+    ///
+    /// Unset and empty variables are treated as absent
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            network: read_env(&NETWORK)?,
+            relayer_url: read_env(&RELAYER_URL)?,
+            auth_url: read_env(&AUTH_URL)?,
+            historical_state_url: read_env(&HISTORICAL_STATE_URL)?,
+            admin_key: read_env(&ADMIN_KEY)?.map(Zeroizing::new),
+            api_key: read_env(&API_KEY)?,
+            api_secret: read_env(&API_SECRET)?.map(Zeroizing::new),
+            private_key: read_env(&PRIVATE_KEY)?.map(Zeroizing::new),
+            keystore: read_env(&KEYSTORE)?.map(PathBuf::from),
+            keystore_password: read_env(&KEYSTORE_PASSWORD)?.map(Zeroizing::new),
+            source: Source::Env,
+        })
+    }
+
+    /// Load settings from a TOML or JSON file, chosen by the file's extension
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| ConfigError::file(path, e))?;
+        let mut settings: Self = match path.extension().and_then(OsStr::to_str) {
+            Some("toml") => toml::from_str(&contents).map_err(|e| ConfigError::file(path, e))?,
+            Some("json") => {
+                serde_json::from_str(&contents).map_err(|e| ConfigError::file(path, e))?
+            },
+            _ => return Err(ConfigError::file(path, "expected a .toml or .json file")),
+        };
+
+        settings.source = Source::File(path.to_path_buf());
+        Ok(settings)
+    }
+
+    /// The network parameters, with any URL overrides applied
+    pub fn network_config(&self) -> Result<NetworkConfig, ConfigError> {
+        let name = self.require(&NETWORK, &self.network)?;
+        let network = Network::from_str(name).map_err(|e| self.invalid(&NETWORK, e))?;
+
+        let mut config = network.config();
+        if let Some(url) = &self.relayer_url {
+            config.relayer_base_url = self.parse_url(&RELAYER_URL, url)?;
+        }
+        if let Some(url) = &self.auth_url {
+            config.auth_base_url = self.parse_url(&AUTH_URL, url)?;
+        }
+        if let Some(url) = &self.historical_state_url {
+            config.historical_state_base_url = self.parse_url(&HISTORICAL_STATE_URL, url)?;
+        }
+
+        Ok(config)
+    }
+
+    /// The source of the account's private key
+    pub fn key_source(&self) -> Result<KeySource, ConfigError> {
+        if let Some(path) = &self.keystore {
+            let password = self.require(&KEYSTORE_PASSWORD, &self.keystore_password)?;
+            let password = Zeroizing::new(password.to_string());
+            return Ok(KeySource::Keystore { path: path.clone(), password });
+        }
+
+        let key = self.private_key.as_deref().ok_or_else(|| {
+            let private_key = self.source.describe(&PRIVATE_KEY);
+            let keystore = self.source.describe(&KEYSTORE);
+            ConfigError::Missing(format!("{private_key} or {keystore}"))
+        })?;
+        Ok(KeySource::PrivateKey(Zeroizing::new(key.to_string())))
+    }
+
+    /// Load the account's private key from its key source
+    pub fn signer(&self) -> Result<PrivateKeySigner, ConfigError> {
+        let setting = if self.keystore.is_some() { &KEYSTORE } else { &PRIVATE_KEY };
+        self.key_source()?.load().map_err(|e| self.invalid(setting, e))
+    }
+
+    /// The admin HMAC key, if one is configured
+    pub fn admin_hmac_key(&self) -> Result<Option<HmacKey>, ConfigError> {
+        self.admin_key
+            .as_deref()
+            .map(|key| HmacKey::from_base64_string(key).map_err(|e| self.invalid(&ADMIN_KEY, e)))
+            .transpose()
+    }
+
+    /// The external match API key and secret
+    pub fn api_credentials(&self) -> Result<(&str, &str), ConfigError> {
+        let key = self.require(&API_KEY, &self.api_key)?;
+        let secret = self.require(&API_SECRET, &self.api_secret)?;
+        HmacKey::from_base64_string(secret).map_err(|e| self.invalid(&API_SECRET, e))?;
+
+        Ok((key, secret))
+    }
+
+    // -----------
+    // | Helpers |
+    // -----------
+
+    /// Get a required setting, erroring if it is absent
+    fn require<'a, T: AsRef<str>>(
+        &self,
+        setting: &Setting,
+        value: &'a Option<T>,
+    ) -> Result<&'a str, ConfigError> {
+        value
+            .as_ref()
+            .map(AsRef::as_ref)
+            .ok_or_else(|| ConfigError::Missing(self.source.describe(setting)))
+    }
+
+    /// Validate a URL setting, returning it without a trailing slash
+    fn parse_url(&self, setting: &Setting, url: &str) -> Result<String, ConfigError> {
+        Url::parse(url).map_err(|e| self.invalid(setting, e))?;
+        Ok(url.trim_end_matches('/').to_string())
+    }
+
+    /// Create an error for a setting with an invalid value
+    #[allow(clippy::needless_pass_by_value)]
+    fn invalid<T: ToString>(&self, setting: &Setting, msg: T) -> ConfigError {
+        ConfigError::Invalid { name: self.source.describe(setting), message: msg.to_string() }
+    }
+}
+
+/// Read a setting from the environment
+fn read_env(setting: &Setting) -> Result<Option<String>, ConfigError> {
+    match env::var(setting.env) {
+        Ok(value) if value.is_empty() => Ok(None),
+        Ok(value) => Ok(Some(value)),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(VarError::NotUnicode(_)) => Err(ConfigError::Invalid {
+            name: Source::Env.describe(setting),
+            message: "not valid unicode".to_string(),
+        }),
+    }
+}
+
+// --------------
+// | Key Source |
+// --------------
+
+/// Where to load an account's private key from
+#[derive(Clone)]
+pub enum KeySource {
+    /// A hex encoded private key
+    PrivateKey(Zeroizing<String>),
+    /// An encrypted JSON keystore
+    Keystore {
+        /// The path to the keystore
+        path: PathBuf,
+        /// The password used to decrypt the keystore
+        password: Zeroizing<String>,
+    },
+}
+
+impl fmt::Debug for KeySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeySource::PrivateKey(_) => f.debug_tuple("PrivateKey").field(&"[REDACTED]").finish(),
+            KeySource::Keystore { path, .. } => f
+                .debug_struct("Keystore")
+                .field("path", path)
+                .field("password", &"[REDACTED]")
+                .finish(),
+        }
+    }
+}
+
+impl KeySource {
+    /// Load the private key
+    pub fn load(&self) -> Result<PrivateKeySigner, String> {
+        match self {
+            KeySource::PrivateKey(key) => {
+                PrivateKeySigner::from_str(key.as_str()).map_err(|e| e.to_string())
+            },
+            KeySource::Keystore { path, password } => {
+                PrivateKeySigner::decrypt_keystore(path, password.as_bytes())
+                    .map_err(|e| e.to_string())
+            },
+        }
+    }
+}
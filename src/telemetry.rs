@@ -0,0 +1,282 @@
+//! Optional instrumentation for SDK calls
+//!
+//! With the `tracing` feature enabled, HTTP requests to the relayer and auth
+//! server run in spans carrying their method and route, and emit events
+//! carrying the status and latency of each request.
+//! Headers and request bodies are never recorded, so API keys and HMAC
+//! signatures stay out of traces.
+//!
+//! With the `metrics` feature enabled, the same hooks record counters, gauges
+//! and histograms through the [`metrics`](https://docs.rs/metrics) facade. The
+//! application is responsible for installing a recorder, e.g. a Prometheus
+//! exporter. Route and topic labels have account, order and mint identifiers
+//! replaced with placeholders to keep label cardinality bounded.
+//!
+//! Without either feature, every hook in this module compiles to a no-op.
+
+use std::time::Duration;
+
+#[cfg(all(feature = "metrics", feature = "external-match-client"))]
+use crate::api_types::{ASSEMBLE_MATCH_BUNDLE_ROUTE, GET_QUOTE_ROUTE};
+
+// -------------
+// | Constants |
+// -------------
+
+/// The total number of HTTP requests, labeled by method, route and status
+#[cfg(feature = "metrics")]
+const HTTP_REQUESTS_TOTAL: &str = "renegade_sdk_http_requests_total";
+/// The latency of HTTP requests in seconds, labeled by method and route
+#[cfg(feature = "metrics")]
+const HTTP_REQUEST_DURATION_SECONDS: &str = "renegade_sdk_http_request_duration_seconds";
+/// The total number of failed HTTP requests, labeled by route and status
+#[cfg(feature = "metrics")]
+const HTTP_ERRORS_TOTAL: &str = "renegade_sdk_http_errors_total";
+/// The total number of quote requests, labeled by outcome
+#[cfg(all(feature = "metrics", feature = "external-match-client"))]
+const QUOTE_REQUESTS_TOTAL: &str = "renegade_sdk_quote_requests_total";
+/// The total number of assemble requests, labeled by outcome
+#[cfg(all(feature = "metrics", feature = "external-match-client"))]
+const ASSEMBLE_REQUESTS_TOTAL: &str = "renegade_sdk_assemble_requests_total";
+/// The total number of websocket reconnections
+#[cfg(all(feature = "metrics", feature = "darkpool-client"))]
+const WEBSOCKET_RECONNECTS_TOTAL: &str = "renegade_sdk_websocket_reconnects_total";
+/// The number of messages queued on a topic channel for its slowest listener
+#[cfg(all(feature = "metrics", feature = "darkpool-client"))]
+const WEBSOCKET_TOPIC_LAG: &str = "renegade_sdk_websocket_topic_lag";
+/// The total number of task waiters that timed out
+#[cfg(all(feature = "metrics", feature = "darkpool-client"))]
+const TASK_WAITER_TIMEOUTS_TOTAL: &str = "renegade_sdk_task_waiter_timeouts_total";
+
+/// The status label used for requests that failed before a response arrived
+#[cfg(feature = "metrics")]
+const TRANSPORT_ERROR_STATUS: &str = "transport";
+/// The placeholder substituted for identifiers in route and topic labels
+#[cfg(feature = "metrics")]
+const ID_PLACEHOLDER: &str = ":id";
+
+// ----------
+// | Macros |
+// ----------
+
+/// Emit a `tracing` event if the `tracing` feature is enabled
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)*);
+    }};
+}
+pub(crate) use trace_event;
+
+// ---------
+// | Hooks |
+// ---------
+
+/// Record the outcome of an HTTP request
+#[cfg_attr(not(any(feature = "tracing", feature = "metrics")), allow(unused_variables))]
+pub(crate) fn record_http_response(
+    method: &str,
+    path: &str,
+    result: &Result<reqwest::Response, reqwest::Error>,
+    latency: Duration,
+) {
+    let route = route_of(path);
+    let latency_ms = latency.as_millis() as u64;
+
+    match result {
+        Ok(resp) if resp.status().is_success() => {
+            let status = resp.status().as_u16();
+            trace_event!(debug, method, route, status, latency_ms, "relayer request completed");
+        },
+        Ok(resp) => {
+            let status = resp.status().as_u16();
+            trace_event!(warn, method, route, status, latency_ms, "relayer request failed");
+        },
+        Err(e) => {
+            trace_event!(warn, method, route, latency_ms, error = %e, "relayer request errored");
+        },
+    }
+
+    #[cfg(feature = "metrics")]
+    record_http_metrics(method, route, result.as_ref().ok().map(|r| r.status()), latency);
+}
+
+/// Record that a request completed with no content, e.g. when no quote or
+/// match was found
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn record_no_content(route: &str) {
+    trace_event!(debug, route, "relayer returned no content");
+}
+
+/// Record the ID of a quote on the current span
+#[cfg(feature = "external-match-client")]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn record_quote_id(quote_id: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("quote_id", quote_id);
+    trace_event!(debug, quote_id, "received quote");
+}
+
+/// Record a websocket reconnection
+#[cfg(feature = "darkpool-client")]
+pub(crate) fn record_websocket_reconnect() {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(WEBSOCKET_RECONNECTS_TOTAL).increment(1);
+}
+
+/// Record the number of messages queued on a topic channel
+#[cfg(feature = "darkpool-client")]
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_topic_lag(topic: &str, queued: usize) {
+    #[cfg(feature = "metrics")]
+    metrics::gauge!(WEBSOCKET_TOPIC_LAG, "topic" => normalize_route(topic)).set(queued as f64);
+}
+
+/// Record a task waiter timing out
+#[cfg(feature = "darkpool-client")]
+pub(crate) fn record_task_timeout() {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(TASK_WAITER_TIMEOUTS_TOTAL).increment(1);
+}
+
+// -----------
+// | Helpers |
+// -----------
+
+/// Strip the query string from a request path, leaving only the route
+#[cfg_attr(not(any(feature = "tracing", feature = "metrics")), allow(dead_code))]
+pub(crate) fn route_of(path: &str) -> &str {
+    path.split('?').next().unwrap_or(path)
+}
+
+/// Record request, error, quote and assemble metrics for an HTTP request
+///
+/// A `status` of `None` indicates the request failed before a response was
+/// received
+#[cfg(feature = "metrics")]
+fn record_http_metrics(
+    method: &str,
+    route: &str,
+    status: Option<reqwest::StatusCode>,
+    latency: Duration,
+) {
+    let method = method.to_string();
+    let route_label = normalize_route(route);
+    let status_label =
+        status.map(|s| s.as_u16().to_string()).unwrap_or_else(|| TRANSPORT_ERROR_STATUS.into());
+
+    metrics::counter!(
+        HTTP_REQUESTS_TOTAL,
+        "method" => method.clone(),
+        "route" => route_label.clone(),
+        "status" => status_label.clone(),
+    )
+    .increment(1);
+    metrics::histogram!(
+        HTTP_REQUEST_DURATION_SECONDS,
+        "method" => method,
+        "route" => route_label.clone(),
+    )
+    .record(latency.as_secs_f64());
+
+    let is_error = status.is_none_or(|s| !s.is_success());
+    if is_error {
+        metrics::counter!(HTTP_ERRORS_TOTAL, "route" => route_label, "status" => status_label)
+            .increment(1);
+    }
+
+    // Quotes and assemblies return 204 when no match is found
+    #[cfg(feature = "external-match-client")]
+    record_match_outcome(route, is_error, status == Some(reqwest::StatusCode::NO_CONTENT));
+}
+
+/// Record the outcome of a quote or assemble request
+///
+/// The v1 external match methods are converted to v2 requests & sent to the
+/// same routes, so they are counted here too
+#[cfg(all(feature = "metrics", feature = "external-match-client"))]
+fn record_match_outcome(route: &str, is_error: bool, no_content: bool) {
+    if route == GET_QUOTE_ROUTE {
+        let outcome = outcome_label(is_error, no_content, "hit", "miss");
+        metrics::counter!(QUOTE_REQUESTS_TOTAL, "outcome" => outcome).increment(1);
+    } else if route == ASSEMBLE_MATCH_BUNDLE_ROUTE {
+        let outcome = outcome_label(is_error, no_content, "success", "no_match");
+        metrics::counter!(ASSEMBLE_REQUESTS_TOTAL, "outcome" => outcome).increment(1);
+    }
+}
+
+/// Pick the outcome label for a request that may return no content
+#[cfg(all(feature = "metrics", feature = "external-match-client"))]
+fn outcome_label(
+    is_error: bool,
+    no_content: bool,
+    found: &'static str,
+    not_found: &'static str,
+) -> &'static str {
+    if is_error {
+        "error"
+    } else if no_content {
+        not_found
+    } else {
+        found
+    }
+}
+
+/// Replace identifiers -- UUIDs, hex addresses and numeric IDs -- in a route
+/// or topic with a placeholder
+#[cfg(feature = "metrics")]
+fn normalize_route(route: &str) -> String {
+    route
+        .split('/')
+        .map(|segment| if is_identifier(segment) { ID_PLACEHOLDER } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Whether a path segment is an identifier rather than a fixed route component
+#[cfg(feature = "metrics")]
+fn is_identifier(segment: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+
+    let is_hex =
+        segment.strip_prefix("0x").is_some_and(|s| s.chars().all(|c| c.is_ascii_hexdigit()));
+    let is_uuid = uuid::Uuid::parse_str(segment).is_ok();
+    let is_numeric = segment.chars().all(|c| c.is_ascii_digit());
+    is_hex || is_uuid || is_numeric
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_identifier() {
+        assert!(is_identifier("3fa85f64-5717-4562-b3fc-2c963f66afa6"));
+        assert!(is_identifier("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        assert!(is_identifier("42"));
+
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("v2"));
+        assert!(!is_identifier("get-quote"));
+        assert!(!is_identifier("0xnothex"));
+    }
+
+    #[test]
+    fn test_normalize_route() {
+        let account_id = "3fa85f64-5717-4562-b3fc-2c963f66afa6";
+        let route = format!("/v2/account/{account_id}/orders/{account_id}");
+        assert_eq!(normalize_route(&route), "/v2/account/:id/orders/:id");
+
+        let route = "/v2/markets/0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48/depth";
+        assert_eq!(normalize_route(route), "/v2/markets/:id/depth");
+
+        // Fixed routes are left unchanged
+        assert_eq!(normalize_route("/v2/markets/depth"), "/v2/markets/depth");
+        assert_eq!(
+            normalize_route("/v2/external-matches/get-quote"),
+            "/v2/external-matches/get-quote"
+        );
+    }
+}
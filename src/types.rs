@@ -1,4 +1,8 @@
-//! Re-exports of v1 types for backwards compatibility
+//! Types shared between the SDK's clients, & re-exports of v1 types for
+//! backwards compatibility
+
+pub mod exchange_metadata;
+pub mod token;
 
 #[cfg(feature = "external-match-client")]
 pub use crate::external_match_client::api_types::{
@@ -1,8 +1,14 @@
 //! Types for the exchange metadata endpoints
+//!
+//! Shared by the external match client & the darkpool client, which reads a
+//! relayer's metadata to configure itself
 
 use serde::{Deserialize, Serialize};
 
-use crate::api_types::token::ApiToken;
+use crate::types::token::ApiToken;
+
+/// Returns metadata about the Renegade exchange
+pub const GET_EXCHANGE_METADATA_ROUTE: &str = "/v2/metadata/exchange";
 
 /// The metadata for the Renegade exchange
 ///